@@ -1,7 +1,5 @@
 use criterion::{Criterion, criterion_group, criterion_main};
-use rustoku::Rustoku;
-use rustoku::core::Board;
-use rustoku::generate_board;
+use rustoku::core::{Backend, Rustoku, RustokuBoard, generate_board};
 use std::hint::black_box;
 
 // Constants for puzzles (can be defined directly or loaded from files)
@@ -15,7 +13,7 @@ fn benchmark_solve_any(c: &mut Criterion) {
 
     // Benchmark `solve_any` for a unique puzzle
     group.bench_function("solve_any_unique", |b| {
-        let board = Board::try_from(UNIQUE_PUZZLE).unwrap();
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).unwrap();
         let rustoku = Rustoku::new(board).unwrap();
         b.iter(|| {
             // Use black_box to prevent the compiler from optimizing away the computation
@@ -25,7 +23,7 @@ fn benchmark_solve_any(c: &mut Criterion) {
 
     // Benchmark `solve_any` for a puzzle with two solutions (might be slightly different behavior)
     group.bench_function("solve_any_two_solutions", |b| {
-        let board = Board::try_from(TWO_PUZZLE).unwrap();
+        let board = RustokuBoard::try_from(TWO_PUZZLE).unwrap();
         let rustoku = Rustoku::new(board).unwrap();
         b.iter(|| {
             black_box(rustoku.clone().solve_any());
@@ -40,7 +38,7 @@ fn benchmark_solve_all(c: &mut Criterion) {
 
     // Benchmark `solve_all` for a unique puzzle
     group.bench_function("solve_all_unique", |b| {
-        let board = Board::try_from(UNIQUE_PUZZLE).unwrap();
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).unwrap();
         let rustoku = Rustoku::new(board).unwrap();
         b.iter(|| {
             black_box(rustoku.clone().solve_all());
@@ -49,7 +47,7 @@ fn benchmark_solve_all(c: &mut Criterion) {
 
     // Benchmark `solve_all` for a puzzle with two solutions
     group.bench_function("solve_all_two_solutions", |b| {
-        let board = Board::try_from(TWO_PUZZLE).unwrap();
+        let board = RustokuBoard::try_from(TWO_PUZZLE).unwrap();
         let rustoku = Rustoku::new(board).unwrap();
         b.iter(|| {
             black_box(rustoku.clone().solve_all());
@@ -59,6 +57,59 @@ fn benchmark_solve_all(c: &mut Criterion) {
     group.finish();
 }
 
+#[cfg(feature = "rayon")]
+fn benchmark_solve_all_parallel(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Solve All Sudoku Puzzles (Serial vs Parallel)");
+
+    // TWO_PUZZLE has multiple solutions, so solve_all has to explore more than one branch,
+    // which is exactly the case solve_all_parallel is meant to speed up.
+    let board = RustokuBoard::try_from(TWO_PUZZLE).unwrap();
+    let rustoku = Rustoku::new(board).unwrap();
+
+    group.bench_function("solve_all_two_solutions_serial", |b| {
+        b.iter(|| {
+            black_box(rustoku.clone().solve_all());
+        });
+    });
+
+    group.bench_function("solve_all_two_solutions_parallel", |b| {
+        b.iter(|| {
+            black_box(rustoku.solve_all_parallel());
+        });
+    });
+
+    group.finish();
+}
+
+fn benchmark_solve_any_backends(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Solve Sudoku Puzzles (Backend Comparison)");
+
+    for (name, puzzle) in [("unique", UNIQUE_PUZZLE), ("two_solutions", TWO_PUZZLE)] {
+        let board = RustokuBoard::try_from(puzzle).unwrap();
+        let rustoku = Rustoku::new(board).unwrap();
+
+        group.bench_function(format!("solve_any_{name}_backtracking"), |b| {
+            b.iter(|| {
+                black_box(rustoku.solve_any_with(Backend::Backtracking));
+            });
+        });
+
+        group.bench_function(format!("solve_any_{name}_dancing_links"), |b| {
+            b.iter(|| {
+                black_box(rustoku.solve_any_with(Backend::DancingLinks));
+            });
+        });
+
+        group.bench_function(format!("solve_any_{name}_sat"), |b| {
+            b.iter(|| {
+                black_box(rustoku.solve_any_with(Backend::Sat));
+            });
+        });
+    }
+
+    group.finish();
+}
+
 fn benchmark_generate_board(c: &mut Criterion) {
     let mut group = c.benchmark_group("Generate Sudoku Puzzles");
 
@@ -79,10 +130,21 @@ fn benchmark_generate_board(c: &mut Criterion) {
     group.finish();
 }
 
+#[cfg(feature = "rayon")]
+criterion_group!(
+    benches,
+    benchmark_solve_any,
+    benchmark_solve_all,
+    benchmark_solve_all_parallel,
+    benchmark_solve_any_backends,
+    benchmark_generate_board
+);
+#[cfg(not(feature = "rayon"))]
 criterion_group!(
     benches,
     benchmark_solve_any,
     benchmark_solve_all,
+    benchmark_solve_any_backends,
     benchmark_generate_board
 );
 criterion_main!(benches);
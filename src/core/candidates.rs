@@ -3,32 +3,43 @@ use super::masks::RustokuMasks;
 
 /// Represents the candidates cache for a Rustoku puzzle.
 ///
-/// This struct holds a 9x9 grid of candidate masks for each cell in the Rustoku board.
-/// Each cell's candidates are represented as a bitmask, where each bit corresponds to a number
-/// from 1 to 9. A bit set to 1 indicates that the corresponding number is a candidate for that cell.
-/// The `RustokuCandidates` struct provides methods to get and set candidate masks for specific cells,
-/// as well as to update the candidates based on the current state of the board and masks.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// This struct holds a `size x size` grid of candidate masks for each cell in the Rustoku board,
+/// where `size` is the board's order squared (9 for classic 9x9, 16 for 16x16, ...). Each cell's
+/// candidates are represented as a bitmask, where each bit corresponds to a number from 1 to
+/// `size`. A bit set to 1 indicates that the corresponding number is a candidate for that cell.
+/// The `RustokuCandidates` struct provides methods to get and set candidate masks for specific
+/// cells, as well as to update the candidates based on the current state of the board and masks.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(super) struct RustokuCandidates {
-    cache: [[u16; 9]; 9],
+    order: usize,
+    cache: Vec<Vec<u32>>,
 }
 
 impl RustokuCandidates {
-    pub(super) fn new() -> Self {
-        RustokuCandidates { cache: [[0; 9]; 9] }
+    pub(super) fn new(order: usize) -> Self {
+        let size = order * order;
+        RustokuCandidates {
+            order,
+            cache: vec![vec![0; size]; size],
+        }
     }
 
     /// Returns the candidate mask for a specific cell in the cache.
-    pub(super) fn get(&self, r: usize, c: usize) -> u16 {
+    pub(super) fn get(&self, r: usize, c: usize) -> u32 {
         self.cache[r][c]
     }
 
     /// Sets the candidate mask for a specific cell in the cache.
-    pub(super) fn set(&mut self, r: usize, c: usize, mask: u16) {
+    pub(super) fn set(&mut self, r: usize, c: usize, mask: u32) {
         self.cache[r][c] = mask;
     }
 
-    /// Update affected cells in the cache based on the current state of the board and masks.
+    /// Incrementally refreshes the cells affected by a placement at `(r, c)`: the placed cell
+    /// itself plus its row, column, and box peers. Called once per [`Rustoku::place_number`] so a
+    /// propagation round never re-scans the whole board — only the roughly `3 * size` cells that
+    /// could actually have changed.
+    ///
+    /// [`Rustoku::place_number`]: super::entrypoint::Rustoku::place_number
     pub(super) fn update_affected_cells(
         &mut self,
         r: usize,
@@ -36,11 +47,13 @@ impl RustokuCandidates {
         masks: &RustokuMasks,
         board: &RustokuBoard,
     ) {
+        let size = self.order * self.order;
+
         // Invalidate/update cache for the placed cell
         self.cache[r][c] = 0; // No candidates for a filled cell
 
         // Update cache for affected row, column, and box
-        for i in 0..9 {
+        for i in 0..size {
             if board.is_empty(r, i) {
                 self.cache[r][i] = masks.compute_candidates_mask_for_cell(r, i);
             }
@@ -50,11 +63,11 @@ impl RustokuCandidates {
         }
 
         // Update box cells
-        let box_idx = RustokuMasks::get_box_idx(r, c);
-        let start_row = (box_idx / 3) * 3;
-        let start_col = (box_idx % 3) * 3;
-        for r_offset in 0..3 {
-            for c_offset in 0..3 {
+        let box_idx = masks.get_box_idx(r, c);
+        let start_row = (box_idx / self.order) * self.order;
+        let start_col = (box_idx % self.order) * self.order;
+        for r_offset in 0..self.order {
+            for c_offset in 0..self.order {
                 let cur_r = start_row + r_offset;
                 let cur_c = start_col + c_offset;
                 if board.is_empty(cur_r, cur_c) {
@@ -0,0 +1,207 @@
+use super::board::RustokuBoard;
+use std::fmt::Debug;
+
+/// A rule that Sudoku variants layer on top of the classic row/column/box rules.
+///
+/// [`Rustoku::with_constraints`](super::Rustoku::with_constraints) attaches a set of these, and
+/// every placement made during solving (backtracking guesses as well as [`Rustoku::is_solved`])
+/// is checked against them in addition to the usual row/column/box safety check.
+///
+/// `Send + Sync` because [`Rustoku`](super::Rustoku) holds these behind an `Arc<dyn Constraint>`
+/// and is itself shared across threads by the rayon-parallel solving paths (`solve_all_parallel`
+/// and friends), which require every field of a value they close over to be thread-safe.
+pub trait Constraint: Debug + Send + Sync {
+    /// Returns whether `board` (with `value` already placed at `(r, c)`, or about to be) still
+    /// satisfies this constraint at `(r, c)`.
+    fn is_satisfied(&self, board: &RustokuBoard, r: usize, c: usize, value: u8) -> bool;
+
+    /// Returns whether every filled cell of a fully-solved `board` satisfies this constraint.
+    fn check(&self, board: &RustokuBoard) -> bool {
+        board
+            .iter_cells()
+            .filter(|&(r, c)| !board.is_empty(r, c))
+            .all(|(r, c)| self.is_satisfied(board, r, c, board.get(r, c)))
+    }
+}
+
+/// X-Sudoku: both main diagonals must also contain each digit at most once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiagonalConstraint;
+
+impl Constraint for DiagonalConstraint {
+    fn is_satisfied(&self, board: &RustokuBoard, r: usize, c: usize, value: u8) -> bool {
+        let size = board.size();
+        let on_main = r == c;
+        let on_anti = r + c == size - 1;
+
+        (!on_main || (0..size).all(|i| i == r || board.get(i, i) != value))
+            && (!on_anti || (0..size).all(|i| i == r || board.get(i, size - 1 - i) != value))
+    }
+}
+
+/// Windoku/hyper-sudoku: a set of extra regions (e.g. the four 3x3 blocks offset one cell in
+/// from the grid's corners) that must also contain each digit at most once.
+#[derive(Debug, Clone)]
+pub struct HyperConstraint {
+    regions: Vec<Vec<(usize, usize)>>,
+}
+
+impl HyperConstraint {
+    /// Builds the four standard windoku regions for a 9x9 board: 3x3 blocks starting at rows
+    /// and columns 1 and 5 (0-indexed).
+    pub fn windoku() -> Self {
+        let regions = [1, 5]
+            .into_iter()
+            .flat_map(|start_row| [1, 5].into_iter().map(move |start_col| (start_row, start_col)))
+            .map(|(start_row, start_col)| {
+                (0..3)
+                    .flat_map(|ro| (0..3).map(move |co| (start_row + ro, start_col + co)))
+                    .collect()
+            })
+            .collect();
+        Self { regions }
+    }
+}
+
+impl Constraint for HyperConstraint {
+    fn is_satisfied(&self, board: &RustokuBoard, r: usize, c: usize, value: u8) -> bool {
+        self.regions
+            .iter()
+            .filter(|region| region.contains(&(r, c)))
+            .all(|region| {
+                region
+                    .iter()
+                    .all(|&(rr, cc)| (rr, cc) == (r, c) || board.get(rr, cc) != value)
+            })
+    }
+}
+
+/// Anti-knight: no two cells a chess knight's-move apart may hold the same digit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AntiKnightConstraint;
+
+const KNIGHT_OFFSETS: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (1, -2),
+    (1, 2),
+    (2, -1),
+    (2, 1),
+];
+
+impl Constraint for AntiKnightConstraint {
+    fn is_satisfied(&self, board: &RustokuBoard, r: usize, c: usize, value: u8) -> bool {
+        let size = board.size() as isize;
+        KNIGHT_OFFSETS.iter().all(|&(dr, dc)| {
+            let nr = r as isize + dr;
+            let nc = c as isize + dc;
+            nr < 0
+                || nr >= size
+                || nc < 0
+                || nc >= size
+                || board.get(nr as usize, nc as usize) != value
+        })
+    }
+}
+
+/// Killer sudoku: a cage of cells with no repeated digit that must sum to `target_sum` once full.
+#[derive(Debug, Clone)]
+pub struct KillerCageConstraint {
+    cells: Vec<(usize, usize)>,
+    target_sum: u32,
+}
+
+impl KillerCageConstraint {
+    /// Creates a cage over `cells` that must sum to exactly `target_sum` with no repeated digit.
+    pub fn new(cells: Vec<(usize, usize)>, target_sum: u32) -> Self {
+        Self { cells, target_sum }
+    }
+}
+
+impl Constraint for KillerCageConstraint {
+    fn is_satisfied(&self, board: &RustokuBoard, r: usize, c: usize, value: u8) -> bool {
+        if !self.cells.contains(&(r, c)) {
+            return true;
+        }
+
+        let mut seen: u32 = 0;
+        let mut sum: u32 = 0;
+        let mut filled = 0usize;
+        for &(rr, cc) in &self.cells {
+            let v = if (rr, cc) == (r, c) { value } else { board.get(rr, cc) };
+            if v == 0 {
+                continue;
+            }
+            if seen & (1 << (v - 1)) != 0 {
+                return false;
+            }
+            seen |= 1 << (v - 1);
+            sum += v as u32;
+            filled += 1;
+        }
+
+        if filled == self.cells.len() {
+            sum == self.target_sum
+        } else {
+            sum <= self.target_sum
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board_with(size: usize, placements: &[(usize, usize, u8)]) -> RustokuBoard {
+        let mut board = RustokuBoard::empty_with_order((size as f64).sqrt() as usize);
+        for &(r, c, v) in placements {
+            board.set(r, c, v);
+        }
+        board
+    }
+
+    #[test]
+    fn test_diagonal_constraint_rejects_repeated_digit_on_main_diagonal() {
+        let board = board_with(9, &[(0, 0, 5)]);
+        let constraint = DiagonalConstraint;
+        assert!(!constraint.is_satisfied(&board, 4, 4, 5));
+        assert!(constraint.is_satisfied(&board, 4, 4, 6));
+    }
+
+    #[test]
+    fn test_diagonal_constraint_ignores_off_diagonal_cells() {
+        let board = board_with(9, &[(0, 0, 5)]);
+        let constraint = DiagonalConstraint;
+        assert!(constraint.is_satisfied(&board, 0, 1, 5));
+    }
+
+    #[test]
+    fn test_hyper_constraint_rejects_repeated_digit_in_window() {
+        let board = board_with(9, &[(1, 1, 7)]);
+        let constraint = HyperConstraint::windoku();
+        assert!(!constraint.is_satisfied(&board, 2, 2, 7));
+        assert!(constraint.is_satisfied(&board, 2, 2, 8));
+    }
+
+    #[test]
+    fn test_anti_knight_constraint_rejects_knights_move_repeat() {
+        let board = board_with(9, &[(0, 0, 3)]);
+        let constraint = AntiKnightConstraint;
+        assert!(!constraint.is_satisfied(&board, 1, 2, 3));
+        assert!(!constraint.is_satisfied(&board, 2, 1, 3));
+        assert!(constraint.is_satisfied(&board, 1, 1, 3));
+    }
+
+    #[test]
+    fn test_killer_cage_constraint_checks_sum_and_uniqueness() {
+        let cage = KillerCageConstraint::new(vec![(0, 0), (0, 1), (0, 2), (0, 3)], 10);
+        let board = board_with(9, &[(0, 0, 3), (0, 1, 3)]);
+        assert!(!cage.is_satisfied(&board, 0, 1, 3)); // duplicate within the cage
+
+        let board = board_with(9, &[(0, 0, 3), (0, 1, 4)]);
+        assert!(cage.is_satisfied(&board, 0, 2, 2)); // running sum still under target, cage not full
+        assert!(!cage.is_satisfied(&board, 0, 2, 8)); // would already overshoot the target sum
+    }
+}
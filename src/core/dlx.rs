@@ -0,0 +1,341 @@
+use super::board::RustokuBoard;
+use super::solution::RustokuSolution;
+use super::techniques::{SolveStep, Technique};
+
+/// Builds the exact-cover matrix for `board`: each `(row, col, value)` candidate covers four
+/// constraints (the cell is filled, the row has that value, the column has that value, the box
+/// has that value), pruned to only the candidates consistent with the given clues. Returns the
+/// matrix alongside the candidate list each chosen row index refers back to.
+fn build_dlx(board: &RustokuBoard) -> (Dlx, Vec<(usize, usize, u8)>) {
+    let n = board.size();
+    let order = board.order;
+
+    let cell_base = 0;
+    let row_base = n * n;
+    let col_base = 2 * n * n;
+    let box_base = 3 * n * n;
+    let num_cols = 4 * n * n;
+
+    let mut candidates: Vec<(usize, usize, u8)> = Vec::new();
+    for r in 0..n {
+        for c in 0..n {
+            let given = board.get(r, c);
+            let values: Vec<u8> = if given != 0 {
+                vec![given]
+            } else {
+                (1..=n as u8).collect()
+            };
+            for v in values {
+                if !conflicts_with_given(board, r, c, v) {
+                    candidates.push((r, c, v));
+                }
+            }
+        }
+    }
+
+    let mut dlx = Dlx::new(num_cols);
+    for (row_idx, &(r, c, v)) in candidates.iter().enumerate() {
+        let box_idx = (r / order) * order + (c / order);
+        let v_idx = (v - 1) as usize;
+        let cols = [
+            cell_base + r * n + c,
+            row_base + r * n + v_idx,
+            col_base + c * n + v_idx,
+            box_base + box_idx * n + v_idx,
+        ];
+        dlx.add_row(&cols, row_idx);
+    }
+
+    (dlx, candidates)
+}
+
+/// Turns a chosen set of candidate-row indices into a filled board and its solve path.
+fn rows_to_solution(board: &RustokuBoard, candidates: &[(usize, usize, u8)], rows: &[usize]) -> RustokuSolution {
+    let mut cells = board.cells.clone();
+    let mut solve_path: Vec<SolveStep> = Vec::new();
+    for &row_idx in rows {
+        let (r, c, v) = candidates[row_idx];
+        if cells[r][c] == 0 {
+            solve_path.push(SolveStep::Placed {
+                r,
+                c,
+                num: v,
+                technique: Technique::Backtrack,
+            });
+        }
+        cells[r][c] = v;
+    }
+
+    RustokuSolution {
+        board: RustokuBoard {
+            order: board.order,
+            cells,
+        },
+        solve_path,
+    }
+}
+
+/// Solves `board` with Knuth's Algorithm X over Dancing Links, treating Sudoku as an exact-cover
+/// problem: each `(row, col, value)` candidate covers four constraints (the cell is filled, the
+/// row has that value, the column has that value, the box has that value), and a solution is a
+/// set of candidates that covers every constraint exactly once.
+pub(super) fn solve_any_dlx(board: &RustokuBoard) -> Option<RustokuSolution> {
+    let (mut dlx, candidates) = build_dlx(board);
+
+    let mut solution_rows = Vec::new();
+    if !dlx.search(&mut solution_rows) {
+        return None;
+    }
+
+    Some(rows_to_solution(board, &candidates, &solution_rows))
+}
+
+/// Counts solutions for `board` using Dancing Links, stopping as soon as `limit` solutions are
+/// found (`limit == 0` means unbounded). Useful for confirming uniqueness (`limit == 2`) without
+/// exploring the full search tree once a second solution is found.
+pub(super) fn solution_count_dlx(board: &RustokuBoard, limit: usize) -> usize {
+    let (mut dlx, _) = build_dlx(board);
+    let mut count = 0usize;
+    let mut rows = Vec::new();
+    dlx.search_all(&mut rows, &mut |_| {
+        count += 1;
+        limit != 0 && count >= limit
+    });
+    count
+}
+
+/// Returns every solution for `board` using Dancing Links.
+pub(super) fn solve_all_dlx(board: &RustokuBoard) -> Vec<RustokuBoard> {
+    solve_until_dlx(board, 0)
+}
+
+/// Returns up to `limit` solutions for `board` using Dancing Links (`limit == 0` means
+/// unbounded), stopping the search as soon as `limit` is reached rather than exploring the
+/// full tree. The bounded counterpart to [`solve_all_dlx`], mirroring how [`Rustoku::solve_until`]
+/// differs from [`Rustoku::solve_all`] on the backtracking path.
+///
+/// [`Rustoku::solve_until`]: super::entrypoint::Rustoku::solve_until
+/// [`Rustoku::solve_all`]: super::entrypoint::Rustoku::solve_all
+pub(super) fn solve_until_dlx(board: &RustokuBoard, limit: usize) -> Vec<RustokuBoard> {
+    let (mut dlx, candidates) = build_dlx(board);
+    let mut rows = Vec::new();
+    let mut boards = Vec::new();
+    dlx.search_all(&mut rows, &mut |chosen| {
+        boards.push(rows_to_solution(board, &candidates, chosen).board);
+        limit != 0 && boards.len() >= limit
+    });
+    boards
+}
+
+fn conflicts_with_given(board: &RustokuBoard, r: usize, c: usize, v: u8) -> bool {
+    let n = board.size();
+    let order = board.order;
+    if (0..n).any(|cc| cc != c && board.get(r, cc) == v) {
+        return true;
+    }
+    if (0..n).any(|rr| rr != r && board.get(rr, c) == v) {
+        return true;
+    }
+    let start_row = (r / order) * order;
+    let start_col = (c / order) * order;
+    (start_row..start_row + order).any(|rr| {
+        (start_col..start_col + order).any(|cc| (rr, cc) != (r, c) && board.get(rr, cc) == v)
+    })
+}
+
+/// An index-based Dancing Links matrix. Node `0` is the root; nodes `1..=num_cols` are column
+/// headers; every node after that is a data cell belonging to some candidate row.
+struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    col: Vec<usize>,
+    size: Vec<usize>,
+    row_id: Vec<usize>,
+}
+
+impl Dlx {
+    fn new(num_cols: usize) -> Self {
+        let num_headers = num_cols + 1;
+        let mut dlx = Self {
+            left: (0..num_headers).collect(),
+            right: (0..num_headers).collect(),
+            up: (0..num_headers).collect(),
+            down: (0..num_headers).collect(),
+            col: (0..num_headers).collect(),
+            size: vec![0; num_headers],
+            row_id: vec![usize::MAX; num_headers],
+        };
+        for i in 0..num_headers {
+            dlx.left[i] = if i == 0 { num_headers - 1 } else { i - 1 };
+            dlx.right[i] = if i == num_headers - 1 { 0 } else { i + 1 };
+        }
+        dlx
+    }
+
+    fn new_node(&mut self, header: usize, row_idx: usize) -> usize {
+        let idx = self.left.len();
+        self.left.push(idx);
+        self.right.push(idx);
+        self.up.push(idx);
+        self.down.push(idx);
+        self.col.push(header);
+        self.row_id.push(row_idx);
+        idx
+    }
+
+    /// Adds a candidate row spanning the given (0-based) constraint columns.
+    fn add_row(&mut self, cols: &[usize], row_idx: usize) {
+        let mut first = None;
+        let mut prev = None;
+        for &col in cols {
+            let header = col + 1;
+            let node = self.new_node(header, row_idx);
+
+            let top = self.up[header];
+            self.up[node] = top;
+            self.down[node] = header;
+            self.down[top] = node;
+            self.up[header] = node;
+            self.size[header] += 1;
+
+            match prev {
+                None => first = Some(node),
+                Some(p) => {
+                    self.right[p] = node;
+                    self.left[node] = p;
+                }
+            }
+            prev = Some(node);
+        }
+        if let (Some(first), Some(last)) = (first, prev) {
+            self.right[last] = first;
+            self.left[first] = last;
+        }
+    }
+
+    fn cover(&mut self, c: usize) {
+        self.right[self.left[c]] = self.right[c];
+        self.left[self.right[c]] = self.left[c];
+        let mut i = self.down[c];
+        while i != c {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.col[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.up[c];
+        while i != c {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.col[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        self.left[self.right[c]] = c;
+        self.right[self.left[c]] = c;
+    }
+
+    /// Runs Algorithm X, choosing the column with the fewest remaining candidates (MRV) at each
+    /// step. On success, `result` holds the candidate row index of each chosen row.
+    fn search(&mut self, result: &mut Vec<usize>) -> bool {
+        if self.right[0] == 0 {
+            return true;
+        }
+
+        let mut c = self.right[0];
+        let mut best = c;
+        while c != 0 {
+            if self.size[c] < self.size[best] {
+                best = c;
+            }
+            c = self.right[c];
+        }
+        if self.size[best] == 0 {
+            return false;
+        }
+
+        self.cover(best);
+        let mut r = self.down[best];
+        while r != best {
+            result.push(self.row_id[r]);
+
+            let mut j = self.right[r];
+            while j != r {
+                self.cover(self.col[j]);
+                j = self.right[j];
+            }
+
+            if self.search(result) {
+                return true;
+            }
+
+            result.pop();
+            let mut j = self.left[r];
+            while j != r {
+                self.uncover(self.col[j]);
+                j = self.left[j];
+            }
+            r = self.down[r];
+        }
+        self.uncover(best);
+        false
+    }
+
+    /// Like [`search`](Self::search), but keeps exploring after finding a solution instead of
+    /// stopping at the first one: `on_solution` is called with the chosen candidate-row indices
+    /// for every complete solution found, and the search stops early once it returns `true`.
+    fn search_all(&mut self, result: &mut Vec<usize>, on_solution: &mut dyn FnMut(&[usize]) -> bool) -> bool {
+        if self.right[0] == 0 {
+            return on_solution(result);
+        }
+
+        let mut c = self.right[0];
+        let mut best = c;
+        while c != 0 {
+            if self.size[c] < self.size[best] {
+                best = c;
+            }
+            c = self.right[c];
+        }
+        if self.size[best] == 0 {
+            return false;
+        }
+
+        self.cover(best);
+        let mut r = self.down[best];
+        let mut stop = false;
+        while !stop && r != best {
+            result.push(self.row_id[r]);
+
+            let mut j = self.right[r];
+            while j != r {
+                self.cover(self.col[j]);
+                j = self.right[j];
+            }
+
+            stop = self.search_all(result, on_solution);
+
+            result.pop();
+            let mut j = self.left[r];
+            while j != r {
+                self.uncover(self.col[j]);
+                j = self.left[j];
+            }
+            r = self.down[r];
+        }
+        self.uncover(best);
+        stop
+    }
+}
@@ -1,11 +1,18 @@
 use super::board::RustokuBoard;
 use super::candidates::RustokuCandidates;
+use super::constraints::Constraint;
+use super::dlx;
 use super::masks::RustokuMasks;
-use super::solution::RustokuSolution;
-use super::techniques::{RustokuTechniques, TechniquePropagator};
+use super::sat;
+use super::solution::{Difficulty, GradeReport, RustokuSolution};
+use super::solver::{Backend, BacktrackingSolver, DancingLinksSolver, SatSolver, Solver};
+use super::techniques::{RustokuTechniques, SolveStep, Technique, TechniquePropagator};
 use crate::error::RustokuError;
 use rand::prelude::SliceRandom;
 use rand::rng;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// A Sudoku primitive that uses backtracking and bitmasking for constraints.
 ///
@@ -42,24 +49,26 @@ use rand::rng;
 /// let rustoku = Rustoku::new(board).unwrap();
 /// assert!(rustoku.is_solved());
 /// ```
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct Rustoku {
     pub board: RustokuBoard,
     masks: RustokuMasks,
     candidates_cache: RustokuCandidates,
     techniques: RustokuTechniques,
+    constraints: Vec<Arc<dyn Constraint>>,
 }
 
 impl Rustoku {
     /// Constructs a new `Rustoku` instance from an initial `Board`.
     pub fn new(initial_board: RustokuBoard) -> Result<Self, RustokuError> {
         let board = initial_board; // Now takes a Board directly
-        let mut masks = RustokuMasks::new();
-        let mut candidates_cache = RustokuCandidates::new();
+        let size = board.size();
+        let mut masks = RustokuMasks::new(board.order);
+        let mut candidates_cache = RustokuCandidates::new(board.order);
 
         // Initialize masks and check for duplicates based on the provided board
-        for r in 0..9 {
-            for c in 0..9 {
+        for r in 0..size {
+            for c in 0..size {
                 let num = board.get(r, c);
                 if num != 0 {
                     if !masks.is_safe(r, c, num) {
@@ -71,8 +80,8 @@ impl Rustoku {
         }
 
         // Initialize the candidates cache for empty cells based on initial masks and board
-        for r in 0..9 {
-            for c in 0..9 {
+        for r in 0..size {
+            for c in 0..size {
                 if board.is_empty(r, c) {
                     candidates_cache.set(r, c, masks.compute_candidates_mask_for_cell(r, c));
                 }
@@ -83,7 +92,8 @@ impl Rustoku {
             board,
             masks,
             candidates_cache,
-            techniques: RustokuTechniques::SINGLES, // Default
+            techniques: RustokuTechniques::EASY, // Default
+            constraints: Vec::new(),
         })
     }
 
@@ -93,15 +103,119 @@ impl Rustoku {
         Self::new(board)
     }
 
+    /// Constructs a new `Rustoku` instance from the sparse coordinate format used by the classic
+    /// Rust shootout Sudoku benchmark: a leading `N,N` dimension line followed by `row,col,value`
+    /// lines (0-based row/col, value `1..=N`, `0` meaning empty). Blank lines are skipped. Useful
+    /// for ingesting externally generated puzzle sets without writing out a full `N^2`-character
+    /// grid.
+    pub fn from_coord_lines(s: &str) -> Result<Self, RustokuError> {
+        let mut lines = s.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        let header = lines.next().ok_or(RustokuError::InvalidCoordRecord)?;
+        let (rows_str, cols_str) = header
+            .split_once(',')
+            .ok_or(RustokuError::InvalidCoordRecord)?;
+        let rows: usize = rows_str
+            .trim()
+            .parse()
+            .map_err(|_| RustokuError::InvalidCoordRecord)?;
+        let cols: usize = cols_str
+            .trim()
+            .parse()
+            .map_err(|_| RustokuError::InvalidCoordRecord)?;
+        if rows != cols {
+            return Err(RustokuError::InvalidCoordRecord);
+        }
+        let size = rows;
+        // Capped at order 5 for the same reason as `RustokuBoard`'s `TryFrom<&str>`: the u32
+        // candidate/mask bitmasks and the formatting module's digit-then-letter alphabet don't
+        // have room past a 25-value board.
+        let order = (1..=5)
+            .find(|&order| order * order == size)
+            .ok_or(RustokuError::InvalidCoordRecord)?;
+
+        let mut board = RustokuBoard::empty_with_order(order);
+        for line in lines {
+            let mut parts = line.split(',').map(str::trim);
+            let r: usize = parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or(RustokuError::InvalidCoordRecord)?;
+            let c: usize = parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or(RustokuError::InvalidCoordRecord)?;
+            let value: u8 = parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or(RustokuError::InvalidCoordRecord)?;
+            if r >= size || c >= size || value as usize > size {
+                return Err(RustokuError::InvalidCoordRecord);
+            }
+            board.set(r, c, value);
+        }
+
+        Self::new(board)
+    }
+
+    /// Serializes this puzzle's board into the sparse coordinate format read by
+    /// [`Rustoku::from_coord_lines`]: a leading `N,N` dimension line followed by one
+    /// `row,col,value` line per non-empty cell.
+    pub fn to_coord_lines(&self) -> String {
+        let size = self.board.size();
+        let mut out = format!("{size},{size}\n");
+        for (r, c) in self.board.iter_cells() {
+            let value = self.board.get(r, c);
+            if value != 0 {
+                out.push_str(&format!("{r},{c},{value}\n"));
+            }
+        }
+        out
+    }
+
     /// Returns the existing Rustoku instance, with modified techniques.
     pub fn with_techniques(mut self, techniques: RustokuTechniques) -> Self {
         self.techniques = techniques;
         self
     }
 
+    /// Returns the techniques to run during constraint propagation.
+    ///
+    /// The human-technique methods in [`super::techniques`] (naked/hidden singles and pairs,
+    /// pointing lines, X-Wing and friends, ...) derive their row/column/box geometry from the
+    /// board's own `order`, so they apply equally to 9x9, 16x16, 25x25, and any other supported
+    /// order.
+    fn effective_techniques(&self) -> RustokuTechniques {
+        self.techniques
+    }
+
+    /// Returns the existing Rustoku instance, with variant rules (diagonal, hyper, anti-knight,
+    /// killer cages, ...) layered on top of the classic row/column/box rules.
+    ///
+    /// These are checked alongside mask safety whenever the backtracking search guesses a value,
+    /// on every placement made by [`Rustoku::solutions`]'s constraint-propagation pass (a rejected
+    /// placement counts as a contradiction), and by [`Rustoku::is_solved`]. The human-technique
+    /// candidate cache itself is not filtered by them.
+    pub fn with_constraints(mut self, constraints: Vec<Arc<dyn Constraint>>) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Returns whether every attached variant constraint is satisfied by placing `num` at
+    /// `(r, c)`.
+    fn constraints_satisfied(&self, r: usize, c: usize, num: u8) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| constraint.is_satisfied(&self.board, r, c, num))
+    }
+
     /// Helper for solver to find the next empty cell (MRV).
+    ///
+    /// Each cell's candidate count is an O(1) lookup against [`RustokuCandidates`], kept current
+    /// incrementally by [`RustokuCandidates::update_affected_cells`] after every placement, rather
+    /// than recomputed from the masks on every call.
     fn find_next_empty_cell(&self) -> Option<(usize, usize)> {
-        let mut min = (10, None); // Min candidates, (r, c)
+        let mut min = (self.board.size() as u8 + 1, None); // Min candidates, (r, c)
         for (r, c) in self.board.iter_empty_cells() {
             let count = self.candidates_cache.get(r, c).count_ones() as u8;
             if count < min.0 {
@@ -114,7 +228,7 @@ impl Rustoku {
         min.1
     }
 
-    /// Place and remove operations for the solver, updated to use the new structs.
+    /// Places a number on the board and updates masks and candidates accordingly.
     fn place_number(&mut self, r: usize, c: usize, num: u8) {
         self.board.set(r, c, num);
         self.masks.add_number(r, c, num);
@@ -122,73 +236,23 @@ impl Rustoku {
             .update_affected_cells(r, c, &self.masks, &self.board);
     }
 
-    /// Remove a number from the board and update masks and candidates.
-    fn remove_number(&mut self, r: usize, c: usize, num: u8) {
-        self.board.set(r, c, 0); // Set back to empty
-        self.masks.remove_number(r, c, num);
-        self.candidates_cache
-            .update_affected_cells(r, c, &self.masks, &self.board);
-        // Note: `update_affected_cells` will recalculate candidates for the removed cell.
-    }
-
-    /// Recursive function to solve the Sudoku puzzle with backtracking.
-    fn solve_until_recursive(
-        &mut self,
-        solutions: &mut Vec<RustokuSolution>,
-        path: &mut Vec<(usize, usize, u8)>,
-        bound: usize,
-    ) -> usize {
-        if let Some((r, c)) = self.find_next_empty_cell() {
-            let mut count = 0;
-            let mut nums: Vec<u8> = (1..=9).collect();
-            nums.shuffle(&mut rng());
-
-            for &num in &nums {
-                if self.masks.is_safe(r, c, num) {
-                    self.place_number(r, c, num);
-                    path.push((r, c, num));
-                    count += self.solve_until_recursive(solutions, path, bound);
-                    path.pop();
-                    self.remove_number(r, c, num);
-
-                    if bound > 0 && solutions.len() >= bound {
-                        return count;
-                    }
-                }
-            }
-            count
-        } else {
-            solutions.push(RustokuSolution {
-                board: self.board,
-                solve_path: path.clone(),
-            });
-            1
-        }
-    }
-
     /// Solves the Sudoku puzzle up to a certain bound, returning solutions with their solve paths.
+    ///
+    /// A `bound` of `0` means "no limit"; otherwise the search stops as soon as `bound`
+    /// solutions have been found. Backed by [`Rustoku::solutions`], so only as much of the
+    /// search tree is explored as needed to reach the bound.
     pub fn solve_until(&mut self, bound: usize) -> Vec<RustokuSolution> {
-        let mut solutions = Vec::new();
-        let mut path = Vec::new();
-
-        let mut propagator = TechniquePropagator::new(
-            &mut self.board,
-            &mut self.masks,
-            &mut self.candidates_cache,
-            self.techniques,
-        );
-
-        if !propagator.propagate_constraints(&mut path, 0) {
-            return solutions; // Early exit if initial constraints are inconsistent
+        let iter = self.solutions();
+        if bound == 0 {
+            iter.collect()
+        } else {
+            iter.take(bound).collect()
         }
-
-        self.solve_until_recursive(&mut solutions, &mut path, bound);
-        solutions
     }
 
     /// Attempts to solve the Sudoku puzzle using backtracking with MRV (Minimum Remaining Values).
     pub fn solve_any(&mut self) -> Option<RustokuSolution> {
-        self.solve_until(1).into_iter().next()
+        self.solutions().next()
     }
 
     /// Finds all possible solutions for the Sudoku puzzle.
@@ -196,8 +260,495 @@ impl Rustoku {
         self.solve_until(0)
     }
 
+    /// Counts solutions using the default backtracking search, stopping as soon as `limit`
+    /// solutions are found (`limit == 0` means unbounded). Unlike [`Rustoku::solve_all`], this
+    /// never clones/collects a [`RustokuSolution`] per branch, so it's the cheap way to ask "is
+    /// this puzzle unique?" (`limit = 2`) or "how many solutions does it have?" (`limit =
+    /// usize::MAX`) without paying for boards you only need the count of. See
+    /// [`Rustoku::solution_count_dlx`] and [`Rustoku::count_solutions_parallel`] for the Dancing
+    /// Links and Rayon-parallel counterparts.
+    pub fn count_solutions(&mut self, limit: usize) -> usize {
+        let iter = self.solutions();
+        if limit == 0 {
+            iter.count()
+        } else {
+            iter.take(limit).count()
+        }
+    }
+
+    /// Grades this puzzle by the techniques needed to solve it, rather than its clue count:
+    /// solves a clone with every technique enabled and reads the grade off the resulting solve
+    /// path. Returns a coarse tier ([`RustokuSolution::difficulty`]), a numeric score
+    /// ([`RustokuSolution::difficulty_score`]) for ranking puzzles within the same tier, and the
+    /// set of techniques the solve path actually invoked, so callers can see exactly which
+    /// logical steps (if any) were needed on top of singles, or that backtracking was required.
+    pub fn rate_difficulty(&self) -> Option<(Difficulty, u32, RustokuTechniques)> {
+        let solution = self
+            .clone()
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()?;
+        Some((
+            solution.difficulty(),
+            solution.difficulty_score(),
+            solution.techniques_used(),
+        ))
+    }
+
+    /// Grades this puzzle into a full [`GradeReport`]: the coarse difficulty tier, how many times
+    /// each technique was invoked, and whether backtracking was required. Like
+    /// [`Rustoku::rate_difficulty`], this solves a clone with every technique enabled rather than
+    /// mutating `self`.
+    pub fn grade(&self) -> Option<GradeReport> {
+        let solution = self
+            .clone()
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()?;
+        Some(GradeReport {
+            difficulty: solution.difficulty(),
+            technique_counts: solution.technique_counts(),
+            requires_guessing: solution.requires_guessing(),
+        })
+    }
+
+    /// Returns how many cells are currently filled in.
+    ///
+    /// A progress metric: pairs with [`Rustoku::remaining_choice_count`] to gauge how much
+    /// searching is left. Each call scans every cell of the board.
+    pub fn solved_cell_count(&self) -> usize {
+        self.board.iter_cells().filter(|&(r, c)| !self.board.is_empty(r, c)).count()
+    }
+
+    /// Returns the total number of remaining candidates summed across every empty cell.
+    ///
+    /// Lower means closer to solved (or closer to a contradiction): a puzzle down to one
+    /// candidate per empty cell is one step from done, while a cell hitting zero candidates
+    /// signals a dead branch in the backtracking search.
+    pub fn remaining_choice_count(&self) -> u32 {
+        self.board
+            .iter_empty_cells()
+            .map(|(r, c)| self.candidates_cache.get(r, c).count_ones())
+            .sum()
+    }
+
+    /// Returns the fraction of cells currently filled in, from `0.0` (empty) to `1.0` (solved).
+    ///
+    /// A board-wide summary of [`Rustoku::solved_cell_count`], handy for driving a progress bar
+    /// or detecting a stalled solve (the rate unchanged across iterations before backtracking
+    /// kicks in) without callers having to know the board's `order`.
+    pub fn solution_rate(&self) -> f64 {
+        let size = self.board.size();
+        self.solved_cell_count() as f64 / (size * size) as f64
+    }
+
+    /// Returns the solution rate of each box, in box order (left-to-right, top-to-bottom).
+    ///
+    /// A per-unit breakdown of [`Rustoku::solution_rate`]: useful for spotting which part of the
+    /// board a solve is stuck on, since a stalled global rate can hide boxes that are already
+    /// complete alongside ones that aren't.
+    pub fn box_solution_rates(&self) -> Vec<f64> {
+        let size = self.board.size();
+        let mut solved = vec![0usize; size];
+        for (r, c) in self.board.iter_cells() {
+            if !self.board.is_empty(r, c) {
+                solved[self.board.box_idx(r, c)] += 1;
+            }
+        }
+        solved.into_iter().map(|n| n as f64 / size as f64).collect()
+    }
+
+    /// Returns the candidate digits (pencil marks) currently cached for `(r, c)`, in ascending
+    /// order. Empty for a filled cell.
+    pub fn candidates(&self, r: usize, c: usize) -> Vec<u8> {
+        let mut mask = self.candidates_cache.get(r, c);
+        let mut candidates = Vec::with_capacity(mask.count_ones() as usize);
+        while mask != 0 {
+            let bit = mask & mask.wrapping_neg();
+            candidates.push(bit.trailing_zeros() as u8 + 1);
+            mask &= !bit;
+        }
+        candidates
+    }
+
     /// Checks if the Sudoku puzzle is solved correctly.
     pub fn is_solved(&self) -> bool {
-        self.board.cells.iter().flatten().all(|&val| val != 0) && Rustoku::new(self.board).is_ok()
+        self.board.cells.iter().flatten().all(|&val| val != 0)
+            && Rustoku::new(self.board.clone()).is_ok()
+            && self
+                .constraints
+                .iter()
+                .all(|constraint| constraint.check(&self.board))
+    }
+
+    /// Returns a lazy iterator over solutions, exploring the backtracking frontier depth-first.
+    ///
+    /// Unlike [`Rustoku::solve_all`], this doesn't collect every solution up front, so callers
+    /// that only need the first one or two solutions (e.g. a uniqueness check via
+    /// `solutions().take(2).count()`) avoid the cost of exploring the rest of the search tree.
+    pub fn solutions(&self) -> SolutionIter {
+        self.solutions_with_mode(SearchMode::DepthFirst)
+    }
+
+    /// Returns a lazy iterator over solutions, exploring the backtracking frontier in the given
+    /// [`SearchMode`].
+    pub fn solutions_with_mode(&self, mode: SearchMode) -> SolutionIter {
+        SolutionIter::new(self.clone(), mode)
+    }
+
+    /// Attempts to find a single solution using the given [`Backend`], letting callers (and
+    /// benchmarks) compare solving strategies on the same puzzle.
+    pub fn solve_any_with(&self, backend: Backend) -> Option<RustokuSolution> {
+        match backend {
+            Backend::Backtracking => BacktrackingSolver {
+                techniques: self.techniques,
+                constraints: self.constraints.clone(),
+            }
+            .solve_any(&self.board),
+            Backend::DancingLinks => DancingLinksSolver.solve_any(&self.board),
+            Backend::Sat => SatSolver.solve_any(&self.board),
+        }
+    }
+
+    /// Attempts to find a single solution by encoding the puzzle as CNF and handing it to a SAT
+    /// solver, instead of the default MRV backtracker. A convenience shorthand for
+    /// `solve_any_with(Backend::Sat)`.
+    ///
+    /// To hand the same CNF encoding to an external solver instead, see
+    /// [`RustokuBoard::to_dimacs`](super::board::RustokuBoard::to_dimacs).
+    pub fn solve_any_sat(&self) -> Option<RustokuSolution> {
+        self.solve_any_with(Backend::Sat)
+    }
+
+    /// Returns every solution for this board using the SAT backend, stopping as soon as `limit`
+    /// solutions are found (`limit == 0` means unbounded). Each solution is excluded via a
+    /// blocking clause before the solver is re-run, so this is a second, independently-verifiable
+    /// way to check uniqueness (or enumerate solutions) alongside [`Rustoku::solve_until`] and
+    /// [`Rustoku::solve_all_dlx`].
+    pub fn solve_all_sat(&self, limit: usize) -> Vec<RustokuSolution> {
+        sat::solve_all_sat(&self.board, limit)
+    }
+
+    /// Counts solutions for this board using the Dancing Links backend, stopping as soon as
+    /// `limit` solutions are found (`limit == 0` means unbounded). Pass `limit = 2` to confirm
+    /// uniqueness without exploring the full search tree, e.g. from a puzzle generator.
+    pub fn solution_count_dlx(&self, limit: usize) -> usize {
+        dlx::solution_count_dlx(&self.board, limit)
+    }
+
+    /// Returns every solution for this board using the Dancing Links backend.
+    pub fn solve_all_dlx(&self) -> Vec<RustokuBoard> {
+        dlx::solve_all_dlx(&self.board)
+    }
+
+    /// Returns up to `limit` solutions for this board using the Dancing Links backend
+    /// (`limit == 0` means unbounded), the bounded counterpart to [`Rustoku::solve_all_dlx`].
+    pub fn solve_until_dlx(&self, limit: usize) -> Vec<RustokuBoard> {
+        dlx::solve_until_dlx(&self.board, limit)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl Rustoku {
+    /// Finds all solutions with a parallel backtracking search: propagates constraints once,
+    /// then forks one Rayon task per candidate at the first branching cell, each continuing the
+    /// search sequentially via [`Rustoku::solutions`].
+    pub fn solve_all_parallel(&self) -> Vec<RustokuSolution> {
+        self.solutions_parallel(0)
+    }
+
+    /// Counts up to `limit` solutions (or all of them, if `limit == 0`) with a parallel
+    /// backtracking search. Useful for uniqueness checks on otherwise slow grids: workers share
+    /// an atomic counter and stop exploring as soon as `limit` solutions have been found by
+    /// anyone, rather than every branch running to completion.
+    pub fn count_solutions_parallel(&self, limit: usize) -> usize {
+        self.solutions_parallel(limit).len()
+    }
+
+    /// Checks whether this puzzle has exactly one solution, using the parallel search's early
+    /// exit at 2 solutions so a non-unique puzzle is rejected as soon as a second solution turns
+    /// up, rather than waiting for every worker to finish. A thin, explicitly-named convenience
+    /// over [`Rustoku::count_solutions_parallel`] for the generator's uniqueness checks.
+    pub fn has_unique_solution(&self) -> bool {
+        self.count_solutions_parallel(2) == 1
+    }
+
+    /// Returns up to `bound` solutions (or all of them, if `bound == 0`) with a parallel
+    /// backtracking search, the parallel counterpart to [`Rustoku::solve_until`]. Like
+    /// [`Rustoku::count_solutions_parallel`], workers share an atomic counter and stop exploring
+    /// as soon as `bound` solutions have been found by anyone.
+    pub fn solve_until_parallel(&self, bound: usize) -> Vec<RustokuSolution> {
+        self.solutions_parallel(bound)
+    }
+
+    fn solutions_parallel(&self, limit: usize) -> Vec<RustokuSolution> {
+        use rayon::prelude::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut seed = self.clone();
+        let mut seed_path: Vec<SolveStep> = Vec::new();
+        {
+            let techniques = seed.effective_techniques();
+            let mut propagator = TechniquePropagator::new(
+                &mut seed.board,
+                &mut seed.masks,
+                &mut seed.candidates_cache,
+                &seed.constraints,
+                techniques,
+            );
+            if !propagator.propagate_constraints(&mut seed_path, 0) {
+                return Vec::new();
+            }
+        }
+
+        let Some((r, c)) = seed.find_next_empty_cell() else {
+            return vec![RustokuSolution {
+                board: seed.board,
+                solve_path: seed_path,
+            }];
+        };
+
+        let branch_values: Vec<u8> = (1..=seed.board.size() as u8)
+            .filter(|&num| seed.masks.is_safe(r, c, num) && seed.constraints_satisfied(r, c, num))
+            .collect();
+
+        // Shared across workers so a branch can stop exploring as soon as enough solutions have
+        // been found anywhere, rather than every branch running to completion before the final
+        // `truncate`. This matters for e.g. a uniqueness check (`limit == 2`) on a grid whose
+        // search tree is otherwise enormous.
+        let found = AtomicUsize::new(0);
+
+        let mut solutions: Vec<RustokuSolution> = branch_values
+            .into_par_iter()
+            .flat_map(|num| {
+                if limit != 0 && found.load(Ordering::Relaxed) >= limit {
+                    return Vec::new();
+                }
+
+                let mut branch_state = seed.clone();
+                branch_state.place_number(r, c, num);
+                let mut branch_path = seed_path.clone();
+                branch_path.push(SolveStep::Placed {
+                    r,
+                    c,
+                    num,
+                    technique: Technique::Backtrack,
+                });
+
+                SolutionIter::new(branch_state, SearchMode::DepthFirst)
+                    .take_while(|_| limit == 0 || found.load(Ordering::Relaxed) < limit)
+                    .map(|mut solution| {
+                        found.fetch_add(1, Ordering::Relaxed);
+                        let mut full_path = branch_path.clone();
+                        full_path.append(&mut solution.solve_path);
+                        RustokuSolution {
+                            board: solution.board,
+                            solve_path: full_path,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        if limit != 0 {
+            solutions.truncate(limit);
+        }
+        solutions
+    }
+}
+
+/// Solves a batch of puzzle strings in parallel with Rayon, one [`Rustoku::new_from_str`] plus
+/// [`Rustoku::solve_any`] per puzzle. `Err` means `puzzle` itself failed to parse; `Ok(None)` means
+/// it parsed but has no solution.
+///
+/// Unlike [`Rustoku::solve_all_parallel`] (which parallelizes the search *within* one puzzle),
+/// this parallelizes *across* puzzles — the shape a batch pipeline (e.g. solving every row of a
+/// CSV file) needs. Results come back in the same order as `puzzles`, since `par_iter().collect()`
+/// preserves input order; batching the file I/O itself is left to the caller.
+#[cfg(feature = "rayon")]
+pub fn solve_batch_parallel(puzzles: &[&str]) -> Vec<Result<Option<RustokuSolution>, RustokuError>> {
+    use rayon::prelude::*;
+    puzzles
+        .par_iter()
+        .map(|&puzzle| Rustoku::new_from_str(puzzle).map(|mut rustoku| rustoku.solve_any()))
+        .collect()
+}
+
+/// The result of [`solve_batch_deduplicated`]: the per-puzzle results in input order, plus how
+/// many of them were answered from the cache instead of being solved again.
+#[derive(Debug)]
+pub struct BatchSolveReport {
+    /// One result per input puzzle, in the same order as the input slice.
+    pub results: Vec<Result<Option<RustokuSolution>, RustokuError>>,
+    /// How many inputs were duplicates of an earlier puzzle in the same batch (after stripping
+    /// whitespace) and were answered from the cache instead of being solved again.
+    pub cache_hits: usize,
+}
+
+/// Solves a batch of puzzle strings, memoizing by the puzzle text with whitespace stripped, so
+/// repeated puzzles within the same batch (common in real-world datasets) are solved once and the
+/// cached result is reused for every later duplicate.
+///
+/// This is the sequential counterpart to [`solve_batch_parallel`]: a cache shared across worker
+/// threads would need synchronization that isn't worth it for the common case of a modest number
+/// of distinct puzzles repeated many times over. Callers wanting both across-puzzle parallelism
+/// and dedup should dedup the input themselves before calling [`solve_batch_parallel`].
+pub fn solve_batch_deduplicated(puzzles: &[&str]) -> BatchSolveReport {
+    let mut cache: std::collections::HashMap<String, Result<Option<RustokuSolution>, RustokuError>> =
+        std::collections::HashMap::new();
+    let mut cache_hits = 0;
+    let results = puzzles
+        .iter()
+        .map(|&puzzle| {
+            let key: String = puzzle.chars().filter(|c| !c.is_whitespace()).collect();
+            if let Some(cached) = cache.get(&key) {
+                cache_hits += 1;
+                return cached.clone();
+            }
+            let result = Rustoku::new_from_str(puzzle).map(|mut rustoku| rustoku.solve_any());
+            cache.insert(key, result.clone());
+            result
+        })
+        .collect();
+    BatchSolveReport { results, cache_hits }
+}
+
+/// The order in which [`SolutionIter`] explores the backtracking frontier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Explore the most recently branched partial solution first (a stack).
+    DepthFirst,
+    /// Explore partial solutions in the order they were branched (a queue).
+    BreadthFirst,
+}
+
+/// Counters describing the work a [`SolutionIter`] has done so far.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SolverStats {
+    /// Number of cells the search branched on, one per empty cell it guessed values for.
+    pub guesses: usize,
+    /// Number of times a guess (or a propagation pass) led to a contradiction.
+    pub backtracks: usize,
+    /// Total number of cells filled across every solution yielded so far.
+    pub cells_filled: usize,
+    /// Wall-clock time spent searching since the iterator was created.
+    pub elapsed: Duration,
+}
+
+/// One node of the backtracking frontier: a snapshot of solver state plus the path taken to
+/// reach it, so the frontier can be kept as owned state rather than a recursive call stack.
+struct Frame {
+    state: Rustoku,
+    path: Vec<SolveStep>,
+}
+
+/// A lazy iterator over the solutions of a [`Rustoku`] puzzle.
+///
+/// Created via [`Rustoku::solutions`] or [`Rustoku::solutions_with_mode`]. Each call to `next`
+/// advances the search only as far as needed to produce one more solution, so callers that stop
+/// early (e.g. after the second solution, to check uniqueness) skip the rest of the search tree.
+pub struct SolutionIter {
+    frontier: VecDeque<Frame>,
+    mode: SearchMode,
+    stats: SolverStats,
+    started_at: Instant,
+}
+
+impl SolutionIter {
+    fn new(start: Rustoku, mode: SearchMode) -> Self {
+        let mut frontier = VecDeque::new();
+        frontier.push_back(Frame {
+            state: start,
+            path: Vec::new(),
+        });
+        Self {
+            frontier,
+            mode,
+            stats: SolverStats::default(),
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Returns the solver statistics accumulated so far, including elapsed wall-clock time.
+    pub fn stats(&self) -> SolverStats {
+        SolverStats {
+            elapsed: self.started_at.elapsed(),
+            ..self.stats
+        }
+    }
+
+    fn pop_frame(&mut self) -> Option<Frame> {
+        match self.mode {
+            SearchMode::DepthFirst => self.frontier.pop_back(),
+            SearchMode::BreadthFirst => self.frontier.pop_front(),
+        }
+    }
+}
+
+impl Iterator for SolutionIter {
+    type Item = RustokuSolution;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(mut frame) = self.pop_frame() {
+            let initial_path_len = frame.path.len();
+            let techniques = frame.state.effective_techniques();
+            let mut propagator = TechniquePropagator::new(
+                &mut frame.state.board,
+                &mut frame.state.masks,
+                &mut frame.state.candidates_cache,
+                &frame.state.constraints,
+                techniques,
+            );
+
+            if !propagator.propagate_constraints(&mut frame.path, initial_path_len) {
+                self.stats.backtracks += 1;
+                continue;
+            }
+
+            match frame.state.find_next_empty_cell() {
+                None => {
+                    self.stats.cells_filled += frame
+                        .path
+                        .iter()
+                        .filter(|step| matches!(step, SolveStep::Placed { .. }))
+                        .count();
+                    return Some(RustokuSolution {
+                        board: frame.state.board,
+                        solve_path: frame.path,
+                    });
+                }
+                Some((r, c)) => {
+                    let mut nums: Vec<u8> = (1..=frame.state.board.size() as u8)
+                        .filter(|&num| {
+                            frame.state.masks.is_safe(r, c, num)
+                                && frame.state.constraints_satisfied(r, c, num)
+                        })
+                        .collect();
+
+                    if nums.is_empty() {
+                        self.stats.backtracks += 1;
+                        continue;
+                    }
+
+                    nums.shuffle(&mut rng());
+                    self.stats.guesses += 1;
+
+                    for num in nums {
+                        let mut next_state = frame.state.clone();
+                        next_state.place_number(r, c, num);
+                        let mut next_path = frame.path.clone();
+                        next_path.push(SolveStep::Placed {
+                            r,
+                            c,
+                            num,
+                            technique: Technique::Backtrack,
+                        });
+                        self.frontier.push_back(Frame {
+                            state: next_state,
+                            path: next_path,
+                        });
+                    }
+                }
+            }
+        }
+        None
     }
 }
@@ -1,33 +1,43 @@
 /// Masks for Rustoku puzzle, representing the state of rows, columns, and boxes.
 ///
-/// This struct holds bitmasks for each row, column, and 3x3 box in the Rustoku board.
-/// Each bit in the masks corresponds to a number from 1 to 9, where a bit set to 1 indicates
+/// This struct holds bitmasks for each row, column, and box in the Rustoku board. Each bit in
+/// the masks corresponds to a number from 1 to `order * order`, where a bit set to 1 indicates
 /// that the corresponding number is present in that row, column, or box.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(super) struct Masks {
-    pub(super) row_masks: [u16; 9],
-    pub(super) col_masks: [u16; 9],
-    pub(super) box_masks: [u16; 9],
+///
+/// Masks are sized (and the box index is computed) according to the board's `order` rather than
+/// a hardcoded 3, so a `RustokuMasks` works for any supported order (3 for classic 9x9, 4 for
+/// 16x16, 5 for 25x25, ...). `u32` bitmasks are used regardless of order since the largest supported board
+/// (order 5, size 25) needs 25 bits, more than a `u16` can hold.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct RustokuMasks {
+    order: usize,
+    full_mask: u32,
+    pub(super) row_masks: Vec<u32>,
+    pub(super) col_masks: Vec<u32>,
+    pub(super) box_masks: Vec<u32>,
 }
 
-impl Masks {
-    pub(super) fn new() -> Self {
-        Masks {
-            row_masks: [0; 9],
-            col_masks: [0; 9],
-            box_masks: [0; 9],
+impl RustokuMasks {
+    pub(super) fn new(order: usize) -> Self {
+        let size = order * order;
+        RustokuMasks {
+            order,
+            full_mask: if size == 32 { u32::MAX } else { (1 << size) - 1 },
+            row_masks: vec![0; size],
+            col_masks: vec![0; size],
+            box_masks: vec![0; size],
         }
     }
 
-    /// Computes the index of the 3x3 box based on the row and column indices.
-    pub(super) fn get_box_idx(r: usize, c: usize) -> usize {
-        (r / 3) * 3 + (c / 3)
+    /// Computes the index of the box based on the row and column indices.
+    pub(super) fn get_box_idx(&self, r: usize, c: usize) -> usize {
+        (r / self.order) * self.order + (c / self.order)
     }
 
     /// Adds a number to the masks for the specified row, column, and box.
     pub(super) fn add_number(&mut self, r: usize, c: usize, num: u8) {
         let bit_to_set = 1 << (num - 1);
-        let box_idx = Self::get_box_idx(r, c);
+        let box_idx = self.get_box_idx(r, c);
         self.row_masks[r] |= bit_to_set;
         self.col_masks[c] |= bit_to_set;
         self.box_masks[box_idx] |= bit_to_set;
@@ -36,7 +46,7 @@ impl Masks {
     /// Removes a number from the masks for the specified row, column, and box.
     pub(super) fn remove_number(&mut self, r: usize, c: usize, num: u8) {
         let bit_to_unset = 1 << (num - 1);
-        let box_idx = Self::get_box_idx(r, c);
+        let box_idx = self.get_box_idx(r, c);
         self.row_masks[r] &= !bit_to_unset;
         self.col_masks[c] &= !bit_to_unset;
         self.box_masks[box_idx] &= !bit_to_unset;
@@ -45,7 +55,7 @@ impl Masks {
     /// Checks if a number can be safely placed in the specified cell.
     pub(super) fn is_safe(&self, r: usize, c: usize, num: u8) -> bool {
         let bit_to_check = 1 << (num - 1);
-        let box_idx = Self::get_box_idx(r, c);
+        let box_idx = self.get_box_idx(r, c);
 
         (self.row_masks[r] & bit_to_check == 0)
             && (self.col_masks[c] & bit_to_check == 0)
@@ -53,11 +63,11 @@ impl Masks {
     }
 
     /// Computes the candidates mask for a specific cell based on the current masks.
-    pub(super) fn compute_candidates_mask_for_cell(&self, r: usize, c: usize) -> u16 {
+    pub(super) fn compute_candidates_mask_for_cell(&self, r: usize, c: usize) -> u32 {
         let row_mask = self.row_masks[r];
         let col_mask = self.col_masks[c];
-        let box_mask = self.box_masks[Self::get_box_idx(r, c)];
+        let box_mask = self.box_masks[self.get_box_idx(r, c)];
         let used = row_mask | col_mask | box_mask;
-        !used & 0x1FF
+        !used & self.full_mask
     }
 }
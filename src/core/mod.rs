@@ -9,19 +9,33 @@
 
 mod board;
 mod candidates;
+mod constraints;
+mod dlx;
 mod entrypoint;
 mod masks;
+mod sat;
 mod solution;
+mod solver;
 mod techniques;
 
 use crate::error::RustokuError;
 pub use board::RustokuBoard;
-pub use entrypoint::Rustoku;
-pub use solution::RustokuSolution;
-pub use techniques::RustokuTechniques;
+pub use constraints::{
+    AntiKnightConstraint, Constraint, DiagonalConstraint, HyperConstraint, KillerCageConstraint,
+};
+#[cfg(feature = "rayon")]
+pub use entrypoint::solve_batch_parallel;
+pub use entrypoint::{
+    BatchSolveReport, Rustoku, SearchMode, SolutionIter, SolverStats, solve_batch_deduplicated,
+};
+pub use solution::{Difficulty, GradeReport, RustokuSolution};
+pub use solver::{Backend, BacktrackingSolver, DancingLinksSolver, SatSolver, Solver};
+pub use techniques::{RustokuTechniques, SolveStep, Technique};
 
 use rand::rng;
 use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 /// Generates a new Sudoku puzzle with a unique solution.
 ///
@@ -44,9 +58,27 @@ pub fn generate_board(num_clues: usize) -> Result<RustokuBoard, RustokuError> {
     if !(17..=81).contains(&num_clues) {
         return Err(RustokuError::InvalidClueCount);
     }
+    generate_board_with_order(board::DEFAULT_ORDER, num_clues)
+}
+
+/// Generates a new Sudoku puzzle with a unique solution on a board of the given `order` (box side
+/// length: `3` for 9x9, `4` for 16x16, `5` for 25x25).
+///
+/// Works like [`generate_board`], except `num_clues` is only bounds-checked against the board's
+/// own size (`order^4` clues at most, at least `order^2`) rather than the proven 9x9 minimum of
+/// 17: outside the standard order the true minimum clue count for a unique solution isn't known,
+/// so this only rejects bounds that are trivially impossible.
+pub fn generate_board_with_order(
+    order: usize,
+    num_clues: usize,
+) -> Result<RustokuBoard, RustokuError> {
+    let size = order * order;
+    if !(size..=size * size).contains(&num_clues) {
+        return Err(RustokuError::InvalidClueCount);
+    }
 
     // Start with a fully solved board
-    let mut rustoku = Rustoku::new(RustokuBoard::empty())?;
+    let mut rustoku = Rustoku::new(RustokuBoard::empty_with_order(order))?;
     let solution = rustoku.solve_any().ok_or(RustokuError::DuplicateValues)?;
     let mut board = solution.board;
 
@@ -54,7 +86,7 @@ pub fn generate_board(num_clues: usize) -> Result<RustokuBoard, RustokuError> {
     let mut cells: Vec<(usize, usize)> = board.iter_cells().collect();
     cells.shuffle(&mut rng());
 
-    let mut clues = 81;
+    let mut clues = size * size;
 
     // Remove numbers while maintaining a unique solution
     for &(r, c) in &cells {
@@ -65,7 +97,7 @@ pub fn generate_board(num_clues: usize) -> Result<RustokuBoard, RustokuError> {
         let original = board.cells[r][c];
         board.cells[r][c] = 0;
 
-        if Rustoku::new(board)?.solve_until(2).len() != 1 {
+        if Rustoku::new(board.clone())?.solve_until(2).len() != 1 {
             board.cells[r][c] = original; // Restore if not unique
         } else {
             clues -= 1;
@@ -73,18 +105,309 @@ pub fn generate_board(num_clues: usize) -> Result<RustokuBoard, RustokuError> {
     }
 
     // Check if the generated puzzle has a unique solution
-    if Rustoku::new(board)?.solve_until(2).len() != 1 {
+    if Rustoku::new(board.clone())?.solve_until(2).len() != 1 {
         // If not unique, return an error
-        return Err(RustokuError::PuzzleGenerationFailed);
+        return Err(RustokuError::GenerateFailure);
     }
 
     Ok(board)
 }
 
+/// A symmetry pattern for [`generate_board_with_symmetry`] to try to preserve while digging out
+/// clues, so the result looks like a hand-crafted puzzle rather than one with clues scattered in
+/// purely random positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// No symmetry constraint; clues are removed in purely random order.
+    None,
+    /// 180-degree rotational symmetry: `(r, c)` is paired with `(size-1-r, size-1-c)`.
+    Rotational,
+    /// Mirror symmetry across the horizontal midline: `(r, c)` is paired with `(size-1-r, c)`.
+    Horizontal,
+    /// Mirror symmetry across the vertical midline: `(r, c)` is paired with `(r, size-1-c)`.
+    Vertical,
+    /// Full dihedral symmetry: `(r, c)`'s orbit under every rotation and reflection of the square
+    /// (up to 8 cells, fewer on the diagonals/center).
+    Dihedral,
+}
+
+impl Symmetry {
+    /// Returns every cell in `(r, c)`'s orbit under this symmetry on a board of side `size`,
+    /// including `(r, c)` itself, with duplicates removed.
+    fn orbit(self, size: usize, r: usize, c: usize) -> Vec<(usize, usize)> {
+        let flip_r = size - 1 - r;
+        let flip_c = size - 1 - c;
+        let mut cells = match self {
+            Symmetry::None => vec![(r, c)],
+            Symmetry::Rotational => vec![(r, c), (flip_r, flip_c)],
+            Symmetry::Horizontal => vec![(r, c), (flip_r, c)],
+            Symmetry::Vertical => vec![(r, c), (r, flip_c)],
+            Symmetry::Dihedral => vec![
+                (r, c),
+                (flip_r, c),
+                (r, flip_c),
+                (flip_r, flip_c),
+                (c, r),
+                (flip_c, r),
+                (c, flip_r),
+                (flip_c, flip_r),
+            ],
+        };
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+}
+
+/// Generates a new Sudoku puzzle with a unique solution on a board of the given `order`, trying
+/// to dig out clues in whole `symmetry` orbits so the result has that visual symmetry.
+///
+/// Cells are visited in random order, but whenever a cell is visited its entire symmetry orbit
+/// (e.g. both ends of a 180-degree rotational pair) is cleared at once, and restored as a whole
+/// if doing so breaks uniqueness (checked with the same [`Rustoku::solve_until`] as
+/// [`generate_board_with_order`]) or would remove more clues than `num_clues` allows. Once no
+/// more whole orbits can be cleared without breaking symmetry or uniqueness, any remaining gap
+/// down to `num_clues` is closed by falling back to [`generate_board_with_order`]'s asymmetric,
+/// cell-at-a-time removal, since a target clue count isn't always reachable while keeping every
+/// orbit intact.
+pub fn generate_board_with_symmetry(
+    order: usize,
+    num_clues: usize,
+    symmetry: Symmetry,
+) -> Result<RustokuBoard, RustokuError> {
+    let size = order * order;
+    if !(size..=size * size).contains(&num_clues) {
+        return Err(RustokuError::InvalidClueCount);
+    }
+
+    let mut rustoku = Rustoku::new(RustokuBoard::empty_with_order(order))?;
+    let solution = rustoku.solve_any().ok_or(RustokuError::DuplicateValues)?;
+    let mut board = solution.board;
+
+    let mut cells: Vec<(usize, usize)> = board.iter_cells().collect();
+    cells.shuffle(&mut rng());
+
+    let mut clues = size * size;
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+
+    // First pass: remove whole symmetry orbits at once, restoring the orbit if it breaks
+    // uniqueness or would overshoot past num_clues.
+    for &(r, c) in &cells {
+        if clues <= num_clues {
+            break;
+        }
+        if visited.contains(&(r, c)) {
+            continue;
+        }
+        let orbit = symmetry.orbit(size, r, c);
+        visited.extend(orbit.iter().copied());
+
+        if orbit.len() > clues - num_clues {
+            continue; // Clearing this whole orbit would remove more clues than allowed.
+        }
+
+        let originals: Vec<u8> = orbit.iter().map(|&(rr, cc)| board.cells[rr][cc]).collect();
+        if originals.iter().all(|&v| v == 0) {
+            continue; // Already empty.
+        }
+        for &(rr, cc) in &orbit {
+            board.cells[rr][cc] = 0;
+        }
+
+        if Rustoku::new(board.clone())?.solve_until(2).len() == 1 {
+            clues -= orbit.len();
+        } else {
+            for (&(rr, cc), &original) in orbit.iter().zip(&originals) {
+                board.cells[rr][cc] = original;
+            }
+        }
+    }
+
+    // Fall back to asymmetric, cell-at-a-time removal for any remaining gap down to num_clues.
+    let mut remaining: Vec<(usize, usize)> = board.iter_cells().collect();
+    remaining.shuffle(&mut rng());
+    for &(r, c) in &remaining {
+        if clues <= num_clues {
+            break;
+        }
+
+        let original = board.cells[r][c];
+        if original == 0 {
+            continue;
+        }
+        board.cells[r][c] = 0;
+
+        if Rustoku::new(board.clone())?.solve_until(2).len() != 1 {
+            board.cells[r][c] = original;
+        } else {
+            clues -= 1;
+        }
+    }
+
+    if Rustoku::new(board.clone())?.solve_until(2).len() != 1 {
+        return Err(RustokuError::GenerateFailure);
+    }
+
+    Ok(board)
+}
+
+/// Generates a new puzzle with a unique solution under `order` and an extra set of variant
+/// constraints (diagonal, anti-knight, windoku, killer cages, ...).
+///
+/// Works like [`generate_board_with_order`], except both the initial solved grid and every
+/// uniqueness check made while digging out clues are produced with `constraints` attached via
+/// [`Rustoku::with_constraints`], so the result is guaranteed unique under the variant's rules,
+/// not just the classic row/column/box rules.
+pub fn generate_board_with_constraints(
+    order: usize,
+    num_clues: usize,
+    constraints: Vec<Arc<dyn Constraint>>,
+) -> Result<RustokuBoard, RustokuError> {
+    let size = order * order;
+    if !(size..=size * size).contains(&num_clues) {
+        return Err(RustokuError::InvalidClueCount);
+    }
+
+    // Start with a fully solved board that already honors the attached constraints.
+    let mut rustoku =
+        Rustoku::new(RustokuBoard::empty_with_order(order))?.with_constraints(constraints.clone());
+    let solution = rustoku.solve_any().ok_or(RustokuError::DuplicateValues)?;
+    let mut board = solution.board;
+
+    let mut cells: Vec<(usize, usize)> = board.iter_cells().collect();
+    cells.shuffle(&mut rng());
+
+    let mut clues = size * size;
+
+    // Remove numbers while maintaining a unique solution under the attached constraints.
+    for &(r, c) in &cells {
+        if clues <= num_clues {
+            break;
+        }
+
+        let original = board.cells[r][c];
+        board.cells[r][c] = 0;
+
+        let unique = Rustoku::new(board.clone())?
+            .with_constraints(constraints.clone())
+            .solve_until(2)
+            .len()
+            == 1;
+        if !unique {
+            board.cells[r][c] = original; // Restore if not unique
+        } else {
+            clues -= 1;
+        }
+    }
+
+    let still_unique = Rustoku::new(board.clone())?
+        .with_constraints(constraints.clone())
+        .solve_until(2)
+        .len()
+        == 1;
+    if !still_unique {
+        return Err(RustokuError::GenerateFailure);
+    }
+
+    Ok(board)
+}
+
+/// A puzzle generated by [`generate_board_with_difficulty`], together with the grading that
+/// accepted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedPuzzle {
+    /// The generated puzzle.
+    pub board: RustokuBoard,
+    /// The puzzle's graded difficulty, matching the `target` requested.
+    pub difficulty: Difficulty,
+    /// The puzzle's numeric difficulty score, from [`RustokuSolution::difficulty_score`], for
+    /// ranking puzzles within the same `difficulty` tier.
+    pub score: u32,
+    /// The number of clues (non-empty cells) left in the puzzle.
+    pub clues: usize,
+}
+
+/// The number of full dig attempts [`generate_board_with_difficulty`] makes before giving up on
+/// hitting the requested [`Difficulty`].
+const MAX_DIFFICULTY_ATTEMPTS: usize = 100;
+
+/// Generates a puzzle with a unique solution that's graded at exactly `target` difficulty.
+///
+/// Starting from a fully solved board, cells are dug out one at a time in random order. After
+/// each removal the candidate board is checked for a unique solution (`solve_until(2)`) and
+/// graded with [`Rustoku::rate_difficulty`]; digging stops and returns as soon as a candidate's
+/// grade matches `target`. A `HashSet` of already-seen board configurations (keyed by their flat
+/// cell values) is kept for the duration of a dig so the same configuration is never re-solved
+/// and re-graded twice. If removing a clue breaks uniqueness, repeats a prior configuration, or
+/// overshoots past `target` (e.g. `Medium` becomes `Hard`), the clue is restored and a different
+/// cell is tried instead. Retries the whole dig up to [`MAX_DIFFICULTY_ATTEMPTS`] times if a
+/// single pass runs out of cells before reaching `target`.
+pub fn generate_board_with_difficulty(target: Difficulty) -> Result<GeneratedPuzzle, RustokuError> {
+    for _ in 0..MAX_DIFFICULTY_ATTEMPTS {
+        let mut rustoku = Rustoku::new(RustokuBoard::empty())?;
+        let solution = rustoku.solve_any().ok_or(RustokuError::DuplicateValues)?;
+        let mut board = solution.board;
+
+        let mut cells: Vec<(usize, usize)> = board.iter_cells().collect();
+        cells.shuffle(&mut rng());
+
+        let mut seen: HashSet<Vec<u8>> = HashSet::new();
+
+        for &(r, c) in &cells {
+            let original = board.cells[r][c];
+            if original == 0 {
+                continue;
+            }
+            board.cells[r][c] = 0;
+
+            let key: Vec<u8> = board.cells.iter().flatten().copied().collect();
+            if !seen.insert(key) {
+                board.cells[r][c] = original; // Already graded this configuration; skip it.
+                continue;
+            }
+
+            let mut candidate = match Rustoku::new(board.clone()) {
+                Ok(candidate) => candidate,
+                Err(_) => {
+                    board.cells[r][c] = original;
+                    continue;
+                }
+            };
+
+            if candidate.solve_until(2).len() != 1 {
+                board.cells[r][c] = original; // Restore if not unique
+                continue;
+            }
+
+            let Some((grade, score, _)) = candidate.rate_difficulty() else {
+                board.cells[r][c] = original;
+                continue;
+            };
+
+            if grade == target {
+                let clues = board.cells.iter().flatten().filter(|&&v| v != 0).count();
+                return Ok(GeneratedPuzzle {
+                    board,
+                    difficulty: grade,
+                    score,
+                    clues,
+                });
+            } else if grade > target {
+                // This clue was load-bearing for an easier grade; put it back and dig elsewhere.
+                board.cells[r][c] = original;
+            }
+            // grade < target: not hard enough yet, keep the clue removed and keep digging.
+        }
+    }
+
+    Err(RustokuError::GenerateFailure)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::board::RustokuBoard;
+    use crate::core::entrypoint::SearchMode;
     use crate::error::RustokuError;
     use crate::format::format_line;
 
@@ -141,6 +464,13 @@ mod tests {
         assert!(matches!(rustoku, Err(RustokuError::InvalidInputLength)));
     }
 
+    #[test]
+    fn test_try_from_rejects_order_6_length_past_the_bitmask_width() {
+        let s = "_".repeat(6 * 6 * 6 * 6);
+        let rustoku = RustokuBoard::try_from(s.as_str());
+        assert!(matches!(rustoku, Err(RustokuError::InvalidInputLength)));
+    }
+
     #[test]
     fn test_try_from_with_invalid_character() {
         let s = "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..7X"; // 'X'
@@ -280,9 +610,9 @@ mod tests {
     fn test_generate_with_enough_clues() {
         (20..=80).step_by(20).for_each(|num_clues| {
             let board = generate_board(num_clues)
-                .expect(&format!("Board generation failed for {} clues", num_clues));
-            let mut rustoku =
-                Rustoku::new(board).expect("Rustoku creation failed from generated board");
+                .unwrap_or_else(|_| panic!("Board generation failed for {} clues", num_clues));
+            let mut rustoku = Rustoku::new(board.clone())
+                .expect("Rustoku creation failed from generated board");
             let clues_count = board
                 .cells
                 .iter()
@@ -306,6 +636,22 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_generate_board_with_order_4() {
+        let board = generate_board_with_order(4, 200)
+            .expect("Board generation failed for a 16x16 board");
+        assert_eq!(4, board.order);
+        assert_eq!(16, board.cells.len());
+
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed from generated board");
+        let solutions = rustoku.solve_all();
+        assert_eq!(
+            1,
+            solutions.len(),
+            "Generated 16x16 puzzle should have a unique solution"
+        );
+    }
+
     #[test]
     fn test_generate_with_too_few_clues() {
         let num_clues = 16;
@@ -320,6 +666,106 @@ mod tests {
         assert!(matches!(result, Err(RustokuError::InvalidClueCount)));
     }
 
+    #[test]
+    fn test_generate_board_with_difficulty_matches_requested_grade() {
+        let puzzle = generate_board_with_difficulty(Difficulty::Easy)
+            .expect("Expected to generate an Easy puzzle within the attempt budget");
+        assert_eq!(Difficulty::Easy, puzzle.difficulty);
+
+        let solution = Rustoku::new(puzzle.board)
+            .expect("Rustoku creation failed from generated board")
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+        assert_eq!(Difficulty::Easy, solution.difficulty());
+        assert_eq!(puzzle.score, solution.difficulty_score());
+    }
+
+    #[test]
+    fn test_rate_difficulty_matches_solve_any_grade() {
+        let puzzle = generate_board_with_difficulty(Difficulty::Easy)
+            .expect("Expected to generate an Easy puzzle within the attempt budget");
+        let rustoku = Rustoku::new(puzzle.board).expect("Rustoku creation failed from generated board");
+
+        let (grade, score, techniques_used) = rustoku
+            .rate_difficulty()
+            .expect("Generated puzzle should be solvable");
+
+        assert_eq!(Difficulty::Easy, grade);
+        assert!(
+            RustokuTechniques::ALL.contains(techniques_used),
+            "techniques_used should only report techniques from RustokuTechniques::ALL"
+        );
+
+        let solution = rustoku
+            .clone()
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+        assert_eq!(solution.difficulty_score(), score);
+    }
+
+    #[test]
+    fn test_grade_reports_difficulty_and_technique_counts() {
+        let puzzle = generate_board_with_difficulty(Difficulty::Easy)
+            .expect("Expected to generate an Easy puzzle within the attempt budget");
+        let rustoku = Rustoku::new(puzzle.board).expect("Rustoku creation failed from generated board");
+
+        let report = rustoku.grade().expect("Generated puzzle should be solvable");
+
+        assert_eq!(report.difficulty, Difficulty::Easy);
+        assert!(!report.requires_guessing);
+        assert!(!report.technique_counts.contains_key(&Technique::Backtrack));
+        assert!(
+            report.technique_counts.values().sum::<usize>() > 0,
+            "An Easy puzzle should still need at least one naked/hidden single"
+        );
+    }
+
+    #[test]
+    fn test_difficulty_score_ranks_expert_above_easy() {
+        // An Expert-rated puzzle (needs at least one backtracking guess) should score higher
+        // than an Easy one, since Technique::Backtrack is weighted far above naked/hidden singles.
+        let easy = generate_board_with_difficulty(Difficulty::Easy)
+            .expect("Expected to generate an Easy puzzle within the attempt budget");
+        let easy_solution = Rustoku::new(easy.board)
+            .expect("Rustoku creation failed from generated board")
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+
+        let expert = generate_board_with_difficulty(Difficulty::Expert)
+            .expect("Expected to generate an Expert puzzle within the attempt budget");
+        let expert_solution = Rustoku::new(expert.board)
+            .expect("Rustoku creation failed from generated board")
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+
+        assert!(expert_solution.difficulty_score() > easy_solution.difficulty_score());
+    }
+
+    #[test]
+    fn test_requires_guessing_matches_expert_difficulty() {
+        let easy = generate_board_with_difficulty(Difficulty::Easy)
+            .expect("Expected to generate an Easy puzzle within the attempt budget");
+        let easy_solution = Rustoku::new(easy.board)
+            .expect("Rustoku creation failed from generated board")
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+        assert!(!easy_solution.requires_guessing());
+
+        let expert = generate_board_with_difficulty(Difficulty::Expert)
+            .expect("Expected to generate an Expert puzzle within the attempt budget");
+        let expert_solution = Rustoku::new(expert.board)
+            .expect("Rustoku creation failed from generated board")
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any()
+            .expect("Generated puzzle should be solvable");
+        assert!(expert_solution.requires_guessing());
+    }
+
     #[test]
     fn test_is_solved_with_valid_solution() {
         let s = UNIQUE_SOLUTION;
@@ -328,6 +774,490 @@ mod tests {
         assert!(rustoku.is_solved(), "The Sudoku puzzle should be solved");
     }
 
+    #[test]
+    fn test_board_empty_with_order_sizes_the_grid() {
+        let board = RustokuBoard::empty_with_order(4);
+        assert_eq!(16, board.size());
+        assert_eq!(16, board.cells.len());
+        assert_eq!(16, board.cells[0].len());
+        assert!(board.cells.iter().flatten().all(|&cell| cell == 0));
+    }
+
+    #[test]
+    fn test_solutions_iter_is_lazy_and_reports_stats() {
+        let s = TWO_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for iterator test");
+        let mut iter = rustoku.solutions();
+        assert_eq!(2, iter.by_ref().take(2).count(), "Expected two solutions");
+        assert!(
+            iter.next().is_none(),
+            "Expected no further solutions beyond the known two"
+        );
+        assert!(iter.stats().cells_filled > 0, "Stats should record filled cells");
+    }
+
+    #[test]
+    fn test_solutions_breadth_first_matches_depth_first() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for BFS test");
+        let solution = rustoku
+            .solutions_with_mode(SearchMode::BreadthFirst)
+            .next()
+            .expect("Expected a solution via breadth-first search");
+        assert_eq!(UNIQUE_SOLUTION, format_line(&solution.board.cells));
+    }
+
+    #[test]
+    fn test_solve_any_with_simple_coloring() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for coloring test");
+        let solution = rustoku
+            .with_techniques(RustokuTechniques::EASY | RustokuTechniques::SIMPLE_COLORING)
+            .solve_any()
+            .expect("Solving with Simple Coloring enabled failed");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&solution.board.cells),
+            "Solution does not match the expected result with Simple Coloring enabled"
+        );
+    }
+
+    #[test]
+    fn test_solve_any_with_xy_chain() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for XY-Chain test");
+        let solution = rustoku
+            .with_techniques(RustokuTechniques::EASY | RustokuTechniques::XY_CHAIN)
+            .solve_any()
+            .expect("Solving with XY-Chain enabled failed");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&solution.board.cells),
+            "Solution does not match the expected result with XY-Chain enabled"
+        );
+    }
+
+    #[test]
+    fn test_solve_any_with_naked_subsets() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku =
+            Rustoku::new_from_str(s).expect("Rustoku creation failed for naked subsets test");
+        let solution = rustoku
+            .with_techniques(RustokuTechniques::EASY | RustokuTechniques::NAKED_SUBSETS)
+            .solve_any()
+            .expect("Solving with Naked Subsets enabled failed");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&solution.board.cells),
+            "Solution does not match the expected result with Naked Subsets enabled"
+        );
+    }
+
+    #[test]
+    fn test_solve_any_with_swordfish_and_jellyfish() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for fish test");
+        let solution = rustoku
+            .with_techniques(
+                RustokuTechniques::EASY
+                    | RustokuTechniques::SWORDFISH
+                    | RustokuTechniques::JELLYFISH,
+            )
+            .solve_any()
+            .expect("Solving with Swordfish and Jellyfish enabled failed");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&solution.board.cells),
+            "Solution does not match the expected result with Swordfish/Jellyfish enabled"
+        );
+    }
+
+    #[test]
+    fn test_solve_any_with_locked_candidates_pointing_and_claiming() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s)
+            .expect("Rustoku creation failed for locked candidates test");
+        let solution = rustoku
+            .with_techniques(
+                RustokuTechniques::EASY
+                    | RustokuTechniques::LOCKED_CANDIDATES_POINTING
+                    | RustokuTechniques::LOCKED_CANDIDATES_CLAIMING,
+            )
+            .solve_any()
+            .expect("Solving with Locked Candidates enabled failed");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&solution.board.cells),
+            "Solution does not match the expected result with Locked Candidates enabled"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_solve_all_parallel_matches_solve_all() {
+        let s = TWO_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for parallel test");
+        let mut solutions = rustoku.clone().solve_all();
+        let mut parallel_solutions = rustoku.solve_all_parallel();
+
+        solutions.sort_by_key(|s| format_line(&s.board.cells));
+        parallel_solutions.sort_by_key(|s| format_line(&s.board.cells));
+
+        assert_eq!(solutions.len(), parallel_solutions.len());
+        assert_eq!(
+            solutions.iter().map(|s| &s.board).collect::<Vec<_>>(),
+            parallel_solutions.iter().map(|s| &s.board).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_count_solutions_parallel_stops_at_limit() {
+        let s = TWO_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for parallel test");
+
+        assert_eq!(rustoku.count_solutions_parallel(1), 1);
+        assert_eq!(rustoku.count_solutions_parallel(2), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_solve_until_parallel_respects_bound() {
+        let s = TWO_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for parallel test");
+
+        let bounded = rustoku.solve_until_parallel(1);
+        assert_eq!(bounded.len(), 1);
+
+        let all = rustoku.solve_until_parallel(0);
+        let sequential = rustoku.clone().solve_all();
+        assert_eq!(all.len(), sequential.len());
+        for solution in &sequential {
+            assert!(all.iter().any(|s| s.board == solution.board));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_solve_batch_parallel_preserves_order_and_reports_per_puzzle_errors() {
+        let too_short = "53..7....";
+        let puzzles = [UNIQUE_PUZZLE, too_short, TWO_PUZZLE];
+
+        let results = solve_batch_parallel(&puzzles);
+        assert_eq!(3, results.len());
+
+        let first = results[0].as_ref().expect("First puzzle should parse");
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&first.as_ref().expect("First puzzle should be solvable").board.cells)
+        );
+
+        assert!(matches!(results[1], Err(RustokuError::InvalidInputLength)));
+
+        let third = results[2].as_ref().expect("Third puzzle should parse");
+        assert!(third.is_some(), "TWO_PUZZLE should be solvable");
+    }
+
+    #[test]
+    fn test_solve_batch_deduplicated_reuses_cached_result_for_repeated_puzzles() {
+        let padded = format!(" {UNIQUE_PUZZLE} ");
+        let puzzles = [UNIQUE_PUZZLE, TWO_PUZZLE, padded.as_str(), UNIQUE_PUZZLE];
+
+        let report = solve_batch_deduplicated(&puzzles);
+        assert_eq!(4, report.results.len());
+        // The third entry is a whitespace-padded duplicate of the first, and the fourth is an
+        // exact duplicate, so both should be served from the cache.
+        assert_eq!(2, report.cache_hits);
+
+        for (i, result) in report.results.iter().enumerate() {
+            if i == 1 {
+                continue;
+            }
+            let solution = result
+                .as_ref()
+                .expect("Puzzle should parse")
+                .as_ref()
+                .expect("Puzzle should be solvable");
+            assert_eq!(UNIQUE_SOLUTION, format_line(&solution.board.cells));
+        }
+    }
+
+    #[test]
+    fn test_solve_any_with_dancing_links_matches_backtracking() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for backend test");
+
+        let dlx_solution = rustoku
+            .solve_any_with(Backend::DancingLinks)
+            .expect("Dancing Links backend failed to solve a unique puzzle");
+        let backtracking_solution = rustoku
+            .solve_any_with(Backend::Backtracking)
+            .expect("Backtracking backend failed to solve a unique puzzle");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&dlx_solution.board.cells),
+            "Dancing Links solution does not match the expected result"
+        );
+        assert_eq!(dlx_solution.board, backtracking_solution.board);
+    }
+
+    #[test]
+    fn test_solve_any_with_sat_matches_backtracking() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for SAT test");
+
+        let sat_solution = rustoku
+            .solve_any_with(Backend::Sat)
+            .expect("SAT backend failed to solve a unique puzzle");
+        let backtracking_solution = rustoku
+            .solve_any_with(Backend::Backtracking)
+            .expect("Backtracking backend failed to solve a unique puzzle");
+
+        assert_eq!(
+            UNIQUE_SOLUTION,
+            format_line(&sat_solution.board.cells),
+            "SAT solution does not match the expected result"
+        );
+        assert_eq!(sat_solution.board, backtracking_solution.board);
+    }
+
+    #[test]
+    fn test_solve_any_sat_matches_solve_any_with_sat() {
+        let s = UNIQUE_PUZZLE;
+        let rustoku = Rustoku::new_from_str(s).expect("Rustoku creation failed for SAT test");
+
+        let solution = rustoku
+            .solve_any_sat()
+            .expect("solve_any_sat failed to solve a unique puzzle");
+
+        assert_eq!(UNIQUE_SOLUTION, format_line(&solution.board.cells));
+    }
+
+    #[test]
+    fn test_to_dimacs_header_matches_clause_count() {
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Board parsing failed");
+        let dimacs = board.to_dimacs();
+
+        let header = dimacs.lines().next().expect("DIMACS output should not be empty");
+        let mut parts = header.split_whitespace();
+        assert_eq!(Some("p"), parts.next());
+        assert_eq!(Some("cnf"), parts.next());
+        let num_vars: usize = parts.next().unwrap().parse().unwrap();
+        let num_clauses: usize = parts.next().unwrap().parse().unwrap();
+
+        assert_eq!(9 * 9 * 9, num_vars);
+        // Every remaining line is one clause terminated by a trailing "0".
+        assert_eq!(num_clauses, dimacs.lines().count() - 1);
+    }
+
+    #[test]
+    fn test_from_dimacs_solution_round_trips_a_solved_board() {
+        let mut rustoku =
+            Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed for DIMACS test");
+        let solution = rustoku.solve_any().expect("Solving failed for DIMACS test");
+
+        // A real SAT solver's model lists every variable, signed by whether it's true; build the
+        // same full assignment here rather than just the cells' true literals.
+        let assignment: String = (0..9)
+            .flat_map(|r| (0..9).flat_map(move |c| (0..9).map(move |d| (r, c, d))))
+            .map(|(r, c, d)| {
+                let var = ((r * 9 + c) * 9 + d + 1) as isize;
+                let is_true = solution.board.get(r, c) as usize - 1 == d;
+                if is_true { var } else { -var }.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+            + " 0";
+
+        let board =
+            RustokuBoard::from_dimacs_solution(&assignment).expect("DIMACS solution parsing failed");
+        assert_eq!(UNIQUE_SOLUTION, format_line(&board.cells));
+    }
+
+    #[test]
+    fn test_from_dimacs_solution_rejects_malformed_input() {
+        let result = RustokuBoard::from_dimacs_solution("1 2 not-a-number 0");
+        assert!(matches!(result, Err(RustokuError::InvalidDimacsSolution)));
+    }
+
+    #[test]
+    fn test_solution_count_dlx_confirms_uniqueness() {
+        let unique = Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed");
+        assert_eq!(unique.solution_count_dlx(2), 1);
+
+        let two = Rustoku::new_from_str(TWO_PUZZLE).expect("Rustoku creation failed");
+        assert_eq!(two.solution_count_dlx(2), 2);
+        assert_eq!(two.solution_count_dlx(0), 2);
+    }
+
+    #[test]
+    fn test_solve_all_dlx_matches_solve_all() {
+        let rustoku = Rustoku::new_from_str(TWO_PUZZLE).expect("Rustoku creation failed");
+
+        let dlx_boards = rustoku.solve_all_dlx();
+        let backtracking_boards: Vec<RustokuBoard> = rustoku
+            .solutions()
+            .map(|solution| solution.board)
+            .collect();
+
+        assert_eq!(dlx_boards.len(), backtracking_boards.len());
+        for board in &backtracking_boards {
+            assert!(dlx_boards.contains(board));
+        }
+    }
+
+    #[test]
+    fn test_solve_until_dlx_respects_limit() {
+        let rustoku = Rustoku::new_from_str(TWO_PUZZLE).expect("Rustoku creation failed");
+        assert_eq!(rustoku.solve_until_dlx(1).len(), 1);
+        assert_eq!(rustoku.solve_until_dlx(0).len(), rustoku.solve_all_dlx().len());
+    }
+
+    #[test]
+    fn test_solve_all_sat_matches_solve_all() {
+        let rustoku = Rustoku::new_from_str(TWO_PUZZLE).expect("Rustoku creation failed");
+
+        let sat_solutions = rustoku.solve_all_sat(0);
+        let backtracking_boards: Vec<RustokuBoard> = rustoku
+            .solutions()
+            .map(|solution| solution.board)
+            .collect();
+
+        assert_eq!(sat_solutions.len(), backtracking_boards.len());
+        for board in &backtracking_boards {
+            assert!(sat_solutions.iter().any(|solution| &solution.board == board));
+        }
+    }
+
+    #[test]
+    fn test_solve_all_sat_respects_limit() {
+        let rustoku = Rustoku::new_from_str(TWO_PUZZLE).expect("Rustoku creation failed");
+        assert_eq!(rustoku.solve_all_sat(1).len(), 1);
+    }
+
+    #[test]
+    fn test_solve_any_on_order_4_board() {
+        // A mostly-empty 16x16 board (order 4): exercises the order-generic masks, candidates
+        // cache, and backtracking path beyond the classic 9x9 case.
+        let board = RustokuBoard::empty_with_order(4);
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed for 16x16 board");
+
+        let solution = rustoku
+            .solve_any()
+            .expect("Backtracking failed to solve an empty 16x16 board");
+
+        assert_eq!(solution.board.order, 4);
+        assert_eq!(solution.board.size(), 16);
+        assert!(
+            solution
+                .board
+                .cells
+                .iter()
+                .flatten()
+                .all(|&cell| (1..=16).contains(&cell)),
+            "Every cell of a solved 16x16 board should hold a value from 1 to 16"
+        );
+
+        let solved = Rustoku::new(solution.board).expect("Re-parsing the solved board failed");
+        assert!(solved.is_solved(), "The solved 16x16 board should be valid");
+    }
+
+    #[test]
+    fn test_solve_any_on_order_4_board_with_all_techniques() {
+        // Human techniques (naked/hidden singles and pairs, locked candidates, fish, ...) derive
+        // their geometry from the board's order, so they should apply on a 16x16 board exactly as
+        // they do on the classic 9x9 case, rather than being skipped in favor of pure backtracking.
+        let board = RustokuBoard::empty_with_order(4);
+        let mut rustoku = Rustoku::new(board)
+            .expect("Rustoku creation failed for 16x16 board")
+            .with_techniques(RustokuTechniques::ALL);
+
+        let solution = rustoku
+            .solve_any()
+            .expect("Solving with all techniques failed on an empty 16x16 board");
+
+        assert_eq!(solution.board.size(), 16);
+        let solved = Rustoku::new(solution.board).expect("Re-parsing the solved board failed");
+        assert!(solved.is_solved(), "The solved 16x16 board should be valid");
+    }
+
+    #[test]
+    fn test_solve_any_on_order_5_board() {
+        // A mostly-empty 25x25 board (order 5): the largest order this crate's `u32` masks and
+        // candidate bitmasks comfortably support (25 bits).
+        let board = RustokuBoard::empty_with_order(5);
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed for 25x25 board");
+
+        let solution = rustoku
+            .solve_any()
+            .expect("Backtracking failed to solve an empty 25x25 board");
+
+        assert_eq!(solution.board.order, 5);
+        assert_eq!(solution.board.size(), 25);
+        let solved = Rustoku::new(solution.board).expect("Re-parsing the solved board failed");
+        assert!(solved.is_solved(), "The solved 25x25 board should be valid");
+    }
+
+    #[test]
+    fn test_from_coord_lines_round_trips_through_solve() {
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Board parsing failed");
+        let coord_lines = Rustoku::new(board)
+            .expect("Rustoku creation failed")
+            .to_coord_lines();
+
+        let mut rustoku = Rustoku::from_coord_lines(&coord_lines)
+            .expect("Parsing coordinate-format puzzle failed");
+        let solution = rustoku.solve_any().expect("Solving puzzle failed");
+        assert_eq!(UNIQUE_SOLUTION, format_line(&solution.board.cells));
+    }
+
+    #[test]
+    fn test_from_coord_lines_rejects_inconsistent_dimensions() {
+        let result = Rustoku::from_coord_lines("9,8\n0,0,5\n");
+        assert!(matches!(result, Err(RustokuError::InvalidCoordRecord)));
+    }
+
+    #[test]
+    fn test_from_coord_lines_accepts_explicit_zero_value() {
+        // The format allows an explicit `row,col,0` triple for a blank cell, not just omitting
+        // the cell entirely, matching the "0 = empty" convention used by external tooling.
+        let rustoku = Rustoku::from_coord_lines("9,9\n0,0,5\n0,1,0\n")
+            .expect("Parsing coordinate-format puzzle with an explicit zero failed");
+        assert!(rustoku.board.is_empty(0, 1));
+    }
+
+    #[test]
+    fn test_from_coord_lines_rejects_out_of_range_value() {
+        let result = Rustoku::from_coord_lines("9,9\n0,0,10\n");
+        assert!(matches!(result, Err(RustokuError::InvalidCoordRecord)));
+    }
+
+    #[test]
+    fn test_from_coord_lines_rejects_order_6_dimensions_past_the_bitmask_width() {
+        let result = Rustoku::from_coord_lines("36,36\n0,0,1\n");
+        assert!(matches!(result, Err(RustokuError::InvalidCoordRecord)));
+    }
+
+    #[test]
+    fn test_to_coord_lines_writes_dimension_header_and_nonblank_cells() {
+        let rustoku = Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed");
+        let coord_lines = rustoku.to_coord_lines();
+
+        let clue_count = UNIQUE_PUZZLE.chars().filter(|&c| c != '.').count();
+        assert_eq!(
+            1 + clue_count,
+            coord_lines.lines().count(),
+            "Expected a header line plus one line per clue"
+        );
+        assert_eq!(Some("9,9"), coord_lines.lines().next());
+    }
+
     #[test]
     fn test_is_solved_with_unsolved_board() {
         let s = UNIQUE_PUZZLE;
@@ -335,4 +1265,140 @@ mod tests {
         let rustoku = Rustoku::new(board).expect("Rustoku creation failed for unsolved check");
         assert!(!rustoku.is_solved(), "The board should not be valid");
     }
+
+    #[test]
+    fn test_solved_cell_count_and_remaining_choice_count_track_progress() {
+        let given_clues = UNIQUE_PUZZLE.chars().filter(|&c| c != '.').count();
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Parsing unsolved puzzle failed");
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed for progress check");
+
+        assert_eq!(rustoku.solved_cell_count(), given_clues);
+        assert!(rustoku.remaining_choice_count() > 0);
+
+        let solution = rustoku.solve_any().expect("Puzzle should be solvable");
+        let solved = Rustoku::new(solution.board).expect("Rustoku creation failed for solved board");
+        assert_eq!(solved.solved_cell_count(), solved.board.size() * solved.board.size());
+        assert_eq!(solved.remaining_choice_count(), 0);
+    }
+
+    #[test]
+    fn test_solution_rate_and_box_solution_rates_track_progress() {
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Parsing unsolved puzzle failed");
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed for progress check");
+
+        let size = rustoku.board.size();
+        assert_eq!(rustoku.solution_rate(), rustoku.solved_cell_count() as f64 / (size * size) as f64);
+        assert!(rustoku.solution_rate() > 0.0 && rustoku.solution_rate() < 1.0);
+
+        let box_rates = rustoku.box_solution_rates();
+        assert_eq!(box_rates.len(), size);
+        assert!(box_rates.iter().all(|&rate| (0.0..=1.0).contains(&rate)));
+
+        let solution = rustoku.solve_any().expect("Puzzle should be solvable");
+        let solved = Rustoku::new(solution.board).expect("Rustoku creation failed for solved board");
+        assert_eq!(solved.solution_rate(), 1.0);
+        assert!(solved.box_solution_rates().iter().all(|&rate| rate == 1.0));
+    }
+
+    #[test]
+    fn test_solve_any_with_techniques_respects_attached_constraints() {
+        use super::DiagonalConstraint;
+        use std::sync::Arc;
+
+        // UNIQUE_PUZZLE's one classic-Sudoku completion (UNIQUE_SOLUTION) repeats digits on both
+        // main diagonals, so no solution can satisfy these givens *and* DiagonalConstraint.
+        // Human techniques must treat an attempted diagonal-violating placement as a
+        // contradiction (not silently complete the board), or solve_any would wrongly return the
+        // classic solution as if it were valid under the attached constraint.
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Parsing puzzle failed");
+        let solution = Rustoku::new(board)
+            .expect("Rustoku creation failed for constraint check")
+            .with_constraints(vec![Arc::new(DiagonalConstraint)])
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any();
+
+        assert!(
+            solution.is_none(),
+            "No solution satisfies both the puzzle's givens and the attached diagonal constraint"
+        );
+    }
+
+    #[test]
+    fn test_solve_any_with_backtracking_backend_respects_attached_constraints() {
+        use super::DiagonalConstraint;
+        use std::sync::Arc;
+
+        // Same setup as test_solve_any_with_techniques_respects_attached_constraints, but
+        // through solve_any_with(Backend::Backtracking): that path used to build a fresh
+        // Rustoku from just the board, silently dropping any attached constraints.
+        let board = RustokuBoard::try_from(UNIQUE_PUZZLE).expect("Parsing puzzle failed");
+        let solution = Rustoku::new(board)
+            .expect("Rustoku creation failed for constraint check")
+            .with_constraints(vec![Arc::new(DiagonalConstraint)])
+            .with_techniques(RustokuTechniques::ALL)
+            .solve_any_with(Backend::Backtracking);
+
+        assert!(
+            solution.is_none(),
+            "No solution satisfies both the puzzle's givens and the attached diagonal constraint"
+        );
+    }
+
+    #[test]
+    fn test_is_solved_respects_attached_constraints() {
+        use super::AntiKnightConstraint;
+        use std::sync::Arc;
+
+        let board = RustokuBoard::try_from(UNIQUE_SOLUTION).expect("Parsing solved puzzle failed");
+        let rustoku = Rustoku::new(board)
+            .expect("Rustoku creation failed for constraint check")
+            .with_constraints(vec![Arc::new(AntiKnightConstraint)]);
+
+        // The classic solution wasn't generated under the anti-knight rule, so some pair of
+        // cells a knight's-move apart collides once that rule is enforced.
+        assert!(!rustoku.is_solved());
+    }
+
+    #[test]
+    fn test_generate_board_with_constraints_respects_anti_knight_uniqueness() {
+        use super::AntiKnightConstraint;
+        use std::sync::Arc;
+
+        let constraints: Vec<Arc<dyn Constraint>> = vec![Arc::new(AntiKnightConstraint)];
+        let board = generate_board_with_constraints(3, 40, constraints.clone())
+            .expect("Puzzle generation with constraints failed");
+
+        let mut rustoku = Rustoku::new(board)
+            .expect("Rustoku creation failed for generated puzzle")
+            .with_constraints(constraints);
+        assert_eq!(rustoku.solve_until(2).len(), 1);
+    }
+
+    #[test]
+    fn test_symmetry_orbit_pairs_cells_correctly() {
+        // 9x9 board (size 9): (0, 0)'s rotational partner is (8, 8), horizontal partner (8, 0),
+        // vertical partner (0, 8).
+        assert_eq!(Symmetry::Rotational.orbit(9, 0, 0), vec![(0, 0), (8, 8)]);
+        assert_eq!(Symmetry::Horizontal.orbit(9, 0, 0), vec![(0, 0), (8, 0)]);
+        assert_eq!(Symmetry::Vertical.orbit(9, 0, 0), vec![(0, 0), (0, 8)]);
+
+        // The center cell of an odd-sized board maps to itself under every symmetry.
+        assert_eq!(Symmetry::Dihedral.orbit(9, 4, 4), vec![(4, 4)]);
+
+        // Dihedral includes the transpose, so an off-diagonal cell has up to 8 distinct images.
+        let orbit = Symmetry::Dihedral.orbit(9, 1, 2);
+        assert_eq!(orbit.len(), 8);
+        assert!(orbit.contains(&(1, 2)));
+        assert!(orbit.contains(&(2, 1))); // transpose
+        assert!(orbit.contains(&(7, 6))); // 180-degree rotation
+    }
+
+    #[test]
+    fn test_generate_board_with_symmetry_has_unique_solution() {
+        let board = generate_board_with_symmetry(3, 40, Symmetry::Rotational)
+            .expect("Puzzle generation with symmetry failed");
+
+        let mut rustoku = Rustoku::new(board).expect("Rustoku creation failed for generated puzzle");
+        assert_eq!(rustoku.solve_until(2).len(), 1);
+    }
 }
@@ -0,0 +1,216 @@
+use super::board::RustokuBoard;
+use super::solution::RustokuSolution;
+use crate::error::RustokuError;
+use varisat::{CnfFormula, ExtendFormula, Lit, Solver as VarisatSolver};
+
+/// Builds the raw CNF clauses encoding `board` as a Sudoku SAT instance, as DIMACS-style signed
+/// variable numbers (negative means negated). Shared by [`solve_any_sat`] (which hands them to an
+/// in-crate CDCL solver) and [`to_dimacs`] (which exports them for external tooling), so the two
+/// never drift apart.
+///
+/// One boolean variable `x(r, c, d)` represents "cell `(r, c)` holds digit `d`", numbered
+/// `1..=size^3`. The encoding: a clause per cell requiring at least one digit, pairwise
+/// at-most-one clauses per cell and per row/column/box occurrence of each digit, and a unit
+/// clause for every given (the "preset before rules" approach).
+fn build_clauses(board: &RustokuBoard) -> Vec<Vec<isize>> {
+    let size = board.size();
+    let order = board.order;
+    let var = |r: usize, c: usize, d: usize| -> isize { ((r * size + c) * size + d + 1) as isize };
+
+    let mut clauses: Vec<Vec<isize>> = Vec::new();
+
+    let at_most_one = |clauses: &mut Vec<Vec<isize>>, vars: &[isize]| {
+        for i in 0..vars.len() {
+            for j in (i + 1)..vars.len() {
+                clauses.push(vec![-vars[i], -vars[j]]);
+            }
+        }
+    };
+
+    // Each cell holds at least one digit.
+    for r in 0..size {
+        for c in 0..size {
+            clauses.push((0..size).map(|d| var(r, c, d)).collect());
+        }
+    }
+
+    // At most one digit per cell.
+    for r in 0..size {
+        for c in 0..size {
+            let vars: Vec<isize> = (0..size).map(|d| var(r, c, d)).collect();
+            at_most_one(&mut clauses, &vars);
+        }
+    }
+
+    // At most one occurrence of each digit per row, column, and box.
+    for d in 0..size {
+        for r in 0..size {
+            let vars: Vec<isize> = (0..size).map(|c| var(r, c, d)).collect();
+            at_most_one(&mut clauses, &vars);
+        }
+        for c in 0..size {
+            let vars: Vec<isize> = (0..size).map(|r| var(r, c, d)).collect();
+            at_most_one(&mut clauses, &vars);
+        }
+        for box_idx in 0..size {
+            let start_row = (box_idx / order) * order;
+            let start_col = (box_idx % order) * order;
+            let vars: Vec<isize> = (0..order)
+                .flat_map(|ro| (0..order).map(move |co| (start_row + ro, start_col + co)))
+                .map(|(r, c)| var(r, c, d))
+                .collect();
+            at_most_one(&mut clauses, &vars);
+        }
+    }
+
+    // Givens are unit clauses.
+    for r in 0..size {
+        for c in 0..size {
+            let value = board.get(r, c);
+            if value != 0 {
+                clauses.push(vec![var(r, c, value as usize - 1)]);
+            }
+        }
+    }
+
+    clauses
+}
+
+/// Exports `board`'s SAT/CNF encoding (see [`build_clauses`]) in DIMACS format, for interop with
+/// external SAT solvers.
+pub(super) fn to_dimacs(board: &RustokuBoard) -> String {
+    let size = board.size();
+    let num_vars = size * size * size;
+    let clauses = build_clauses(board);
+
+    let mut out = format!("p cnf {} {}\n", num_vars, clauses.len());
+    for clause in &clauses {
+        for lit in clause {
+            out.push_str(&lit.to_string());
+            out.push(' ');
+        }
+        out.push_str("0\n");
+    }
+    out
+}
+
+/// Reconstructs a board from a DIMACS solution line (whitespace-separated signed literals, one
+/// per `x(r, c, d)` variable, terminated by a trailing `0`), as emitted by external SAT solvers.
+/// The inverse of [`to_dimacs`]'s variable numbering.
+pub(super) fn from_dimacs_solution(s: &str) -> Result<RustokuBoard, RustokuError> {
+    let literals: Vec<isize> = s
+        .split_whitespace()
+        .map(|tok| tok.parse::<isize>().map_err(|_| RustokuError::InvalidDimacsSolution))
+        .collect::<Result<_, _>>()?;
+    let literals: Vec<isize> = literals.into_iter().filter(|&lit| lit != 0).collect();
+
+    let num_vars = literals.len();
+    let size = (num_vars as f64).cbrt().round() as usize;
+    if size * size * size != num_vars {
+        return Err(RustokuError::InvalidDimacsSolution);
+    }
+    let order = (size as f64).sqrt().round() as usize;
+    if order * order != size {
+        return Err(RustokuError::InvalidDimacsSolution);
+    }
+
+    let mut board = RustokuBoard::empty_with_order(order);
+    for lit in literals {
+        if lit > 0 {
+            let idx = (lit - 1) as usize;
+            let d = idx % size;
+            let c = (idx / size) % size;
+            let r = idx / (size * size);
+            board.set(r, c, (d + 1) as u8);
+        }
+    }
+    Ok(board)
+}
+
+/// Encodes `board` into CNF and hands it to a CDCL SAT solver, decoding the first satisfying
+/// assignment back into a board.
+pub(super) fn solve_any_sat(board: &RustokuBoard) -> Option<RustokuSolution> {
+    let size = board.size();
+    let order = board.order;
+
+    let mut formula = CnfFormula::new();
+    for clause in build_clauses(board) {
+        let lits: Vec<Lit> = clause.into_iter().map(Lit::from_dimacs).collect();
+        formula.add_clause(&lits);
+    }
+
+    let mut solver = VarisatSolver::new();
+    solver.add_formula(&formula);
+    if !solver.solve().ok()? {
+        return None;
+    }
+
+    let mut cells = vec![vec![0u8; size]; size];
+    for lit in solver.model()? {
+        if !lit.is_positive() {
+            continue;
+        }
+        let idx = lit.var().to_dimacs() as usize - 1;
+        let d = idx % size;
+        let c = (idx / size) % size;
+        let r = idx / (size * size);
+        cells[r][c] = d as u8 + 1;
+    }
+
+    Some(RustokuSolution {
+        board: RustokuBoard { order, cells },
+        solve_path: Vec::new(),
+    })
+}
+
+/// Encodes `board` into CNF and enumerates every satisfying assignment, decoding each one back
+/// into a board. Stops as soon as `limit` solutions are found (`limit == 0` means unbounded).
+///
+/// Each solution found is excluded from the search by adding a blocking clause over its true
+/// "cell holds digit" literals before resolving, so repeated calls to the solver never revisit
+/// the same assignment.
+pub(super) fn solve_all_sat(board: &RustokuBoard, limit: usize) -> Vec<RustokuSolution> {
+    let size = board.size();
+    let order = board.order;
+
+    let mut formula = CnfFormula::new();
+    for clause in build_clauses(board) {
+        let lits: Vec<Lit> = clause.into_iter().map(Lit::from_dimacs).collect();
+        formula.add_clause(&lits);
+    }
+
+    let mut solver = VarisatSolver::new();
+    solver.add_formula(&formula);
+
+    let mut solutions = Vec::new();
+    while limit == 0 || solutions.len() < limit {
+        match solver.solve() {
+            Ok(true) => {}
+            _ => break,
+        }
+        let Some(model) = solver.model() else {
+            break;
+        };
+
+        let mut cells = vec![vec![0u8; size]; size];
+        let mut blocking_clause = Vec::new();
+        for lit in model {
+            if !lit.is_positive() {
+                continue;
+            }
+            let idx = lit.var().to_dimacs() as usize - 1;
+            let d = idx % size;
+            let c = (idx / size) % size;
+            let r = idx / (size * size);
+            cells[r][c] = d as u8 + 1;
+            blocking_clause.push(!lit);
+        }
+        solver.add_clause(&blocking_clause);
+
+        solutions.push(RustokuSolution {
+            board: RustokuBoard { order, cells },
+            solve_path: Vec::new(),
+        });
+    }
+    solutions
+}
@@ -1,14 +1,145 @@
-use super::board::Board;
+use super::board::RustokuBoard;
+use super::techniques::{RustokuTechniques, SolveStep, Technique};
+use std::collections::HashMap;
 
-/// Solved board and its solution path.
+/// How hard a puzzle is to solve by hand, graded from the toughest technique its solve path
+/// required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    /// Solvable with naked and hidden singles alone.
+    Easy,
+    /// Requires naked or hidden pairs.
+    Medium,
+    /// Requires naked/hidden subsets, locked candidates, fish (X-Wing, Swordfish, Jellyfish), or
+    /// chains.
+    Hard,
+    /// Requires backtracking: no human technique could make progress at some point.
+    Expert,
+}
+
+impl Technique {
+    fn difficulty(self) -> Difficulty {
+        match self {
+            Technique::NakedSingle | Technique::HiddenSingle => Difficulty::Easy,
+            Technique::NakedPair | Technique::HiddenPair => Difficulty::Medium,
+            Technique::HiddenSubset
+            | Technique::NakedSubset
+            | Technique::LockedCandidatesPointing
+            | Technique::LockedCandidatesClaiming
+            | Technique::XWing
+            | Technique::Swordfish
+            | Technique::Jellyfish
+            | Technique::SimpleColoring
+            | Technique::XyChain => Difficulty::Hard,
+            Technique::Backtrack => Difficulty::Expert,
+        }
+    }
+
+    /// How expensive a single application of this technique is, for [`RustokuSolution::difficulty_score`].
+    ///
+    /// Unlike [`Technique::difficulty`], which only cares about the hardest technique reached,
+    /// these weights are summed across every step of a solve path, so a puzzle that leans on one
+    /// hard technique many times over scores higher than one that needs it just once.
+    fn weight(self) -> u32 {
+        match self {
+            Technique::NakedSingle | Technique::HiddenSingle => 1,
+            Technique::NakedPair | Technique::HiddenPair => 3,
+            Technique::HiddenSubset
+            | Technique::NakedSubset
+            | Technique::LockedCandidatesPointing
+            | Technique::LockedCandidatesClaiming => 5,
+            Technique::XWing | Technique::Swordfish => 8,
+            // One rung above X-Wing/Swordfish: a Jellyfish's 4-line cover set is strictly harder
+            // to spot than the 2- and 3-line fish it shares a difficulty tier with.
+            Technique::Jellyfish => 9,
+            Technique::SimpleColoring | Technique::XyChain => 10,
+            Technique::Backtrack => 20,
+        }
+    }
+}
+
+/// A solved board and the sequence of moves that produced it.
+///
+/// Most callers just want the solved board, but `solve_path` is kept around since it's useful
+/// for debugging, for explaining how a puzzle was solved, and for grading its difficulty via
+/// [`RustokuSolution::difficulty`], [`RustokuSolution::difficulty_score`], and
+/// [`RustokuSolution::technique_counts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustokuSolution {
+    /// The solved board.
+    pub board: RustokuBoard,
+    /// The sequence of placement and elimination steps made to reach this solution.
+    pub solve_path: Vec<SolveStep>,
+}
+
+impl RustokuSolution {
+    /// Grades the difficulty of this solution from the hardest technique its solve path
+    /// required. A solve path with no moves (an already-solved board) is graded `Easy`.
+    pub fn difficulty(&self) -> Difficulty {
+        self.solve_path
+            .iter()
+            .map(|step| step.technique().difficulty())
+            .max()
+            .unwrap_or(Difficulty::Easy)
+    }
+
+    /// Returns whether this solve path had to fall back on a backtracking guess at some point,
+    /// rather than being fully solvable by logical deduction alone.
+    ///
+    /// Equivalent to `self.difficulty() == Difficulty::Expert`, but named for the common case of
+    /// wanting to flag a puzzle as "requires guessing" without comparing against the enum.
+    pub fn requires_guessing(&self) -> bool {
+        self.solve_path
+            .iter()
+            .any(|step| step.technique() == Technique::Backtrack)
+    }
+
+    /// Scores the difficulty of this solution by summing each step's [`Technique::weight`].
+    ///
+    /// Where [`RustokuSolution::difficulty`] only reflects the single hardest technique reached,
+    /// this adds up every step of the solve path, so a puzzle that repeatedly needs a hard
+    /// technique scores higher than one that only needs it once. Useful for ranking puzzles
+    /// within the same coarse [`Difficulty`] tier.
+    pub fn difficulty_score(&self) -> u32 {
+        self.solve_path
+            .iter()
+            .map(|step| step.technique().weight())
+            .sum()
+    }
+
+    /// Returns the set of human techniques actually invoked by this solve path.
+    ///
+    /// [`Technique::Backtrack`] moves don't contribute a flag, since a guess isn't a logical
+    /// technique.
+    pub fn techniques_used(&self) -> RustokuTechniques {
+        self.solve_path
+            .iter()
+            .fold(RustokuTechniques::NONE, |flags, step| {
+                flags | step.technique().as_flag()
+            })
+    }
+
+    /// Counts how many times each [`Technique`] (including [`Technique::Backtrack`]) was invoked
+    /// over this solve path.
+    pub fn technique_counts(&self) -> HashMap<Technique, usize> {
+        let mut counts = HashMap::new();
+        for step in &self.solve_path {
+            *counts.entry(step.technique()).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// A difficulty report for a puzzle, as returned by [`Rustoku::grade`](super::entrypoint::Rustoku::grade).
 ///
-/// Most of the time, users just want to see the solved board, but this struct also
-/// provides the sequence of moves that led to the solution, which can be useful for debugging
-/// or understanding the solving process.
-#[derive(Debug, Clone)]
-pub struct Solution {
-    /// The solved Sudoku board, represented as a 2D array
-    pub board: Board,
-    /// The sequence of moves (row, col, value) made to reach the solution
-    pub solve_path: Vec<(usize, usize, u8)>,
+/// Unlike the coarse [`Difficulty`] tier alone, this keeps the full per-technique breakdown of a
+/// solve path around, so callers can see not just how hard a puzzle is but what made it that hard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GradeReport {
+    /// The tier of the hardest technique the solve path required.
+    pub difficulty: Difficulty,
+    /// How many times each technique was invoked over the solve path.
+    pub technique_counts: HashMap<Technique, usize>,
+    /// Whether the solve path had to fall back on a backtracking guess at some point.
+    pub requires_guessing: bool,
 }
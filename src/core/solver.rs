@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use super::board::RustokuBoard;
+use super::constraints::Constraint;
+use super::dlx;
+use super::entrypoint::Rustoku;
+use super::sat;
+use super::solution::RustokuSolution;
+use super::techniques::RustokuTechniques;
+
+/// A pluggable Sudoku solving strategy.
+///
+/// Implementations only need to find a single solution for a board; [`Rustoku::solve_any_with`]
+/// is the primary entry point, letting callers (and benchmarks) compare strategies on the same
+/// puzzle.
+pub trait Solver {
+    /// Attempts to find a single solution for `board`.
+    fn solve_any(&self, board: &RustokuBoard) -> Option<RustokuSolution>;
+}
+
+/// Which [`Solver`] backend [`Rustoku::solve_any_with`] should use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// The default bitmask-and-backtracking solver, optionally aided by human techniques.
+    Backtracking,
+    /// An exact-cover solver using Dancing Links (Algorithm X).
+    DancingLinks,
+    /// A CNF encoding handed to a CDCL SAT solver.
+    Sat,
+}
+
+/// The default solver: bitmask-based backtracking with MRV, optionally aided by human
+/// techniques. Delegates to [`Rustoku::solve_any`].
+pub struct BacktrackingSolver {
+    /// The human techniques to apply before falling back to a guess.
+    pub techniques: RustokuTechniques,
+    /// Extra constraints (e.g. a [`super::constraints::KillerCageConstraint`]) the caller's
+    /// `Rustoku` had attached via `with_constraints`, which must be preserved here so this
+    /// backend rejects completions the caller's instance would have rejected too.
+    pub constraints: Vec<Arc<dyn Constraint>>,
+}
+
+impl Solver for BacktrackingSolver {
+    fn solve_any(&self, board: &RustokuBoard) -> Option<RustokuSolution> {
+        Rustoku::new(board.clone())
+            .ok()?
+            .with_techniques(self.techniques)
+            .with_constraints(self.constraints.clone())
+            .solve_any()
+    }
+}
+
+/// An exact-cover solver using Dancing Links (Algorithm X). Ignores human techniques entirely;
+/// it searches the raw exact-cover matrix.
+pub struct DancingLinksSolver;
+
+impl Solver for DancingLinksSolver {
+    fn solve_any(&self, board: &RustokuBoard) -> Option<RustokuSolution> {
+        dlx::solve_any_dlx(board)
+    }
+}
+
+/// A SAT-based solver: encodes the puzzle as CNF (one variable per cell/digit, clauses for cell,
+/// row, column, and box constraints) and hands it to a CDCL SAT solver. Ignores human
+/// techniques; its solve path is always empty since the model gives no notion of "how" a cell
+/// was filled.
+pub struct SatSolver;
+
+impl Solver for SatSolver {
+    fn solve_any(&self, board: &RustokuBoard) -> Option<RustokuSolution> {
+        sat::solve_any_sat(board)
+    }
+}
@@ -1,7 +1,10 @@
 use super::board::RustokuBoard;
 use super::candidates::RustokuCandidates;
+use super::constraints::Constraint;
 use super::masks::RustokuMasks;
 use bitflags::bitflags;
+use std::collections::VecDeque;
+use std::sync::Arc;
 
 bitflags! {
     /// A bitmask to control which human techniques are applied.
@@ -18,28 +21,299 @@ bitflags! {
         const NAKED_PAIRS = 1 << 2;
         /// Apply the hidden pairs technique.
         const HIDDEN_PAIRS = 1 << 3;
-        /// Apply the locked candidates technique.
-        const LOCKED_CANDIDATES = 1 << 4;
+        /// Apply the locked candidates technique's pointing direction (row/column confines a
+        /// candidate to one box, eliminating it from the rest of that box).
+        const LOCKED_CANDIDATES_POINTING = 1 << 4;
         /// Apply the X-Wing technique.
         const X_WING = 1 << 5;
+        /// Apply the Simple Coloring (single-digit chaining) technique.
+        const SIMPLE_COLORING = 1 << 6;
+        /// Apply the XY-Chain technique.
+        const XY_CHAIN = 1 << 7;
+        /// Apply the Swordfish (size-3 fish) technique.
+        const SWORDFISH = 1 << 8;
+        /// Apply the Jellyfish (size-4 fish) technique.
+        const JELLYFISH = 1 << 9;
+        /// Apply the hidden subsets technique (hidden triples and quads).
+        const HIDDEN_SUBSETS = 1 << 10;
+        /// Apply the naked subsets technique (naked triples and quads).
+        const NAKED_SUBSETS = 1 << 11;
+        /// Apply the locked candidates technique's claiming direction (box confines a candidate
+        /// to one row/column, eliminating it from the rest of that row/column).
+        const LOCKED_CANDIDATES_CLAIMING = 1 << 12;
 
         /// Apply easy techniques like naked singles and hidden singles.
         const EASY = Self::NAKED_SINGLES.bits() | Self::HIDDEN_SINGLES.bits();
         /// Apply medium techniques like naked pairs and hidden pairs.
         const MEDIUM = Self::NAKED_PAIRS.bits() | Self::HIDDEN_PAIRS.bits();
-        /// Apply hard techniques like locked candidates and X-Wings.
-        const HARD = Self::LOCKED_CANDIDATES.bits() | Self::X_WING.bits();
+        /// Apply hard techniques like locked candidates, fish, and chains.
+        const HARD = Self::LOCKED_CANDIDATES_POINTING.bits()
+            | Self::LOCKED_CANDIDATES_CLAIMING.bits()
+            | Self::X_WING.bits()
+            | Self::SIMPLE_COLORING.bits()
+            | Self::XY_CHAIN.bits()
+            | Self::SWORDFISH.bits()
+            | Self::JELLYFISH.bits()
+            | Self::HIDDEN_SUBSETS.bits()
+            | Self::NAKED_SUBSETS.bits();
         /// Apply all available human-like techniques
         const ALL = Self::EASY.bits() | Self::MEDIUM.bits() | Self::HARD.bits();
     }
 }
 
+/// Which strategy produced a single move in a solve path.
+///
+/// Placements made by [`TechniquePropagator`] are tagged with the human technique that found
+/// them; placements made while backtracking (no logical technique applied) are tagged
+/// [`Technique::Backtrack`]. [`RustokuSolution::difficulty`](super::solution::RustokuSolution::difficulty)
+/// grades a puzzle from the hardest technique its solve path required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Technique {
+    /// A cell had exactly one remaining candidate.
+    NakedSingle,
+    /// A candidate could only go in one cell within a row, column, or box.
+    HiddenSingle,
+    /// Two cells in a unit shared the same two-candidate pair, eliminating it elsewhere.
+    NakedPair,
+    /// Two candidates were confined to the same two cells in a unit.
+    HiddenPair,
+    /// Three or four candidates were confined to that many cells in a unit (hidden triple/quad).
+    HiddenSubset,
+    /// Three or four cells in a unit collectively held only that many candidates (naked triple/quad).
+    NakedSubset,
+    /// A candidate within a row or column was confined to one box, eliminating it from the rest
+    /// of that box.
+    LockedCandidatesPointing,
+    /// A candidate within a box was confined to one row or column, eliminating it from the rest
+    /// of that row/column.
+    LockedCandidatesClaiming,
+    /// A candidate formed an X-Wing pattern across two rows/columns.
+    XWing,
+    /// A candidate formed a Swordfish pattern across three rows/columns.
+    Swordfish,
+    /// A candidate formed a Jellyfish pattern across four rows/columns.
+    Jellyfish,
+    /// A candidate was eliminated by two-coloring a chain of conjugate pairs (Simple Coloring).
+    SimpleColoring,
+    /// A candidate was eliminated by an alternating chain of bivalue cells (XY-Chain).
+    XyChain,
+    /// No logical technique applied; the move was a backtracking guess.
+    Backtrack,
+}
+
+impl Technique {
+    /// Returns the [`RustokuTechniques`] flag that finds this technique, or [`RustokuTechniques::NONE`]
+    /// for [`Technique::Backtrack`], which isn't a logical technique at all.
+    pub(super) fn as_flag(self) -> RustokuTechniques {
+        match self {
+            Technique::NakedSingle => RustokuTechniques::NAKED_SINGLES,
+            Technique::HiddenSingle => RustokuTechniques::HIDDEN_SINGLES,
+            Technique::NakedPair => RustokuTechniques::NAKED_PAIRS,
+            Technique::HiddenPair => RustokuTechniques::HIDDEN_PAIRS,
+            Technique::HiddenSubset => RustokuTechniques::HIDDEN_SUBSETS,
+            Technique::NakedSubset => RustokuTechniques::NAKED_SUBSETS,
+            Technique::LockedCandidatesPointing => RustokuTechniques::LOCKED_CANDIDATES_POINTING,
+            Technique::LockedCandidatesClaiming => RustokuTechniques::LOCKED_CANDIDATES_CLAIMING,
+            Technique::XWing => RustokuTechniques::X_WING,
+            Technique::Swordfish => RustokuTechniques::SWORDFISH,
+            Technique::Jellyfish => RustokuTechniques::JELLYFISH,
+            Technique::SimpleColoring => RustokuTechniques::SIMPLE_COLORING,
+            Technique::XyChain => RustokuTechniques::XY_CHAIN,
+            Technique::Backtrack => RustokuTechniques::NONE,
+        }
+    }
+}
+
+/// Returns whether `a` and `b` share a row, column, or box, for boxes of side length `order`.
+fn same_unit(a: (usize, usize), b: (usize, usize), order: usize) -> bool {
+    a.0 == b.0 || a.1 == b.1 || (a.0 / order == b.0 / order && a.1 / order == b.1 / order)
+}
+
+/// Returns the `order * order` cells of box `box_idx` (boxes numbered left-to-right,
+/// top-to-bottom, `0..size`), for boxes of side length `order`.
+fn box_cells(order: usize, box_idx: usize) -> Vec<(usize, usize)> {
+    let start_row = (box_idx / order) * order;
+    let start_col = (box_idx % order) * order;
+    (0..order)
+        .flat_map(|r_offset| (0..order).map(move |c_offset| (start_row + r_offset, start_col + c_offset)))
+        .collect()
+}
+
+/// Returns every `k`-element subset of `items`, as index-combinations enumerated over the
+/// `items.len() < 32` bitmasks with exactly `k` bits set.
+fn combinations(items: &[usize], k: usize) -> Vec<Vec<usize>> {
+    let n = items.len();
+    (0u32..(1 << n))
+        .filter(|mask| mask.count_ones() as usize == k)
+        .map(|mask| (0..n).filter(|i| mask & (1 << i) != 0).map(|i| items[i]).collect())
+        .collect()
+}
+
+/// Computes the X-Wing eliminations for a single digit against a read-only board/candidates
+/// snapshot, so the digits can be scanned concurrently without aliasing a shared
+/// `&mut RustokuCandidates`.
+#[cfg(feature = "rayon")]
+fn x_wing_eliminations_for_digit(
+    board: &RustokuBoard,
+    candidates: &RustokuCandidates,
+    candidate_val: u8,
+) -> Vec<(usize, usize, u8)> {
+    let size = board.size();
+    let candidate_bit: u32 = 1 << (candidate_val - 1);
+    let mut eliminations = Vec::new();
+    let has_candidate =
+        |r: usize, c: usize| board.is_empty(r, c) && (candidates.get(r, c) & candidate_bit) != 0;
+
+    // Row-based X-Wings.
+    let rows_with_two: Vec<(usize, Vec<usize>)> = (0..size)
+        .filter_map(|r| {
+            let cols: Vec<usize> = (0..size).filter(|&c| has_candidate(r, c)).collect();
+            (cols.len() == 2).then_some((r, cols))
+        })
+        .collect();
+    for i in 0..rows_with_two.len() {
+        for j in (i + 1)..rows_with_two.len() {
+            let (r1, cols1) = &rows_with_two[i];
+            let (r2, cols2) = &rows_with_two[j];
+            if cols1 != cols2 {
+                continue;
+            }
+            for &c in cols1 {
+                for r_other in 0..size {
+                    if r_other != *r1 && r_other != *r2 && has_candidate(r_other, c) {
+                        eliminations.push((r_other, c, candidate_val));
+                    }
+                }
+            }
+        }
+    }
+
+    // Column-based X-Wings.
+    let cols_with_two: Vec<(usize, Vec<usize>)> = (0..size)
+        .filter_map(|c| {
+            let rows: Vec<usize> = (0..size).filter(|&r| has_candidate(r, c)).collect();
+            (rows.len() == 2).then_some((c, rows))
+        })
+        .collect();
+    for i in 0..cols_with_two.len() {
+        for j in (i + 1)..cols_with_two.len() {
+            let (c1, rows1) = &cols_with_two[i];
+            let (c2, rows2) = &cols_with_two[j];
+            if rows1 != rows2 {
+                continue;
+            }
+            for &r in rows1 {
+                for c_other in 0..size {
+                    if c_other != *c1 && c_other != *c2 && has_candidate(r, c_other) {
+                        eliminations.push((r, c_other, candidate_val));
+                    }
+                }
+            }
+        }
+    }
+
+    eliminations
+}
+
+/// Holds the parts of an XY-Chain search that stay constant across the recursion, so the
+/// recursive step only needs to thread the parts that actually change (current cell, the value
+/// it's forced to, which cells are already on the chain, and how deep we are).
+struct XyChainSearch<'a> {
+    cells: &'a [((usize, usize), [u8; 2])],
+    target: u8,
+    max_len: usize,
+    order: usize,
+}
+
+impl XyChainSearch<'_> {
+    /// Depth-first search over the bivalue-cell graph for an XY-Chain ending back at `target`.
+    ///
+    /// `forced_value` is the candidate the cell at `current_idx` is currently forced to hold;
+    /// continuing the chain requires a neighbor that shares `forced_value` and sees the current
+    /// cell, which is then forced onto its other candidate. Returns the index of the first cell
+    /// whose forced candidate equals `target`, completing the chain.
+    fn find_end(
+        &self,
+        current_idx: usize,
+        forced_value: u8,
+        visited: &mut [bool],
+        depth: usize,
+    ) -> Option<usize> {
+        if depth >= self.max_len {
+            return None;
+        }
+        let (current_cell, _) = self.cells[current_idx];
+
+        for next_idx in 0..self.cells.len() {
+            if visited[next_idx] {
+                continue;
+            }
+            let (next_cell, next_vals) = self.cells[next_idx];
+            if !same_unit(current_cell, next_cell, self.order)
+                || !next_vals.contains(&forced_value)
+            {
+                continue;
+            }
+
+            let next_forced = if next_vals[0] == forced_value {
+                next_vals[1]
+            } else {
+                next_vals[0]
+            };
+
+            if next_forced == self.target {
+                return Some(next_idx);
+            }
+
+            visited[next_idx] = true;
+            if let Some(found) = self.find_end(next_idx, next_forced, visited, depth + 1) {
+                return Some(found);
+            }
+            visited[next_idx] = false;
+        }
+
+        None
+    }
+}
+
+/// A single step of a solve path: either a value placed on the board, or a candidate ruled out
+/// of a cell without (yet) being placed — both tagged with the technique responsible, so the
+/// path can drive a step-by-step explanation of how a puzzle was solved, not just the end state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SolveStep {
+    /// `num` was placed at `(r, c)` by `technique`.
+    Placed {
+        r: usize,
+        c: usize,
+        num: u8,
+        technique: Technique,
+    },
+    /// `candidate` was eliminated from `(r, c)` by `technique`, without a value being placed.
+    Eliminated {
+        r: usize,
+        c: usize,
+        candidate: u8,
+        technique: Technique,
+    },
+}
+
+impl SolveStep {
+    /// Returns the technique responsible for this step, whichever variant it is.
+    pub fn technique(&self) -> Technique {
+        match *self {
+            SolveStep::Placed { technique, .. } => technique,
+            SolveStep::Eliminated { technique, .. } => technique,
+        }
+    }
+}
+
 // Now the actual implementation of the techniques, these would operate on
 // references to Board, Masks, and CandidatesCache.
 pub(super) struct TechniquePropagator<'a> {
     board: &'a mut RustokuBoard,
     masks: &'a mut RustokuMasks,
     candidates_cache: &'a mut RustokuCandidates,
+    constraints: &'a [Arc<dyn Constraint>],
     techniques_enabled: RustokuTechniques,
 }
 
@@ -48,29 +322,71 @@ impl<'a> TechniquePropagator<'a> {
         board: &'a mut RustokuBoard,
         masks: &'a mut RustokuMasks,
         candidates_cache: &'a mut RustokuCandidates,
+        constraints: &'a [Arc<dyn Constraint>],
         techniques_enabled: RustokuTechniques,
     ) -> Self {
         Self {
             board,
             masks,
             candidates_cache,
+            constraints,
             techniques_enabled,
         }
     }
 
-    /// Helper to place a number and update caches.
+    /// Returns whether every attached variant constraint (diagonal, hyper, killer cage, ...)
+    /// allows `num` at `(r, c)`, mirroring [`Rustoku::constraints_satisfied`].
+    ///
+    /// [`Rustoku::constraints_satisfied`]: super::entrypoint::Rustoku::constraints_satisfied
+    fn constraints_satisfied(&self, r: usize, c: usize, num: u8) -> bool {
+        self.constraints
+            .iter()
+            .all(|constraint| constraint.is_satisfied(self.board, r, c, num))
+    }
+
+    /// Helper to place a number, update caches, and record which technique made the move.
+    ///
+    /// If a variant constraint rejects `num` here, the logical deduction that led to this
+    /// placement was actually a contradiction under the active constraint set: instead of placing
+    /// `num`, every candidate of the cell is cleared, so [`Self::propagate_constraints`]'s existing
+    /// empty-candidates check can catch the contradiction and roll the branch back.
     fn place_and_update(
         &mut self,
         r: usize,
         c: usize,
         num: u8,
-        path: &mut Vec<(usize, usize, u8)>,
+        path: &mut Vec<SolveStep>,
+        technique: Technique,
     ) {
+        if !self.constraints_satisfied(r, c, num) {
+            self.eliminate_candidates(r, c, 0, path, technique);
+            return;
+        }
         self.board.set(r, c, num);
         self.masks.add_number(r, c, num);
         self.candidates_cache
             .update_affected_cells(r, c, self.masks, self.board);
-        path.push((r, c, num));
+        path.push(SolveStep::Placed { r, c, num, technique });
+    }
+
+    /// Sets cell `(r, c)`'s candidate mask to `new_mask` and records one [`SolveStep::Eliminated`]
+    /// per candidate that `new_mask` ruled out relative to the cache's previous entry.
+    fn eliminate_candidates(
+        &mut self,
+        r: usize,
+        c: usize,
+        new_mask: u32,
+        path: &mut Vec<SolveStep>,
+        technique: Technique,
+    ) {
+        let mut removed = self.candidates_cache.get(r, c) & !new_mask;
+        self.candidates_cache.set(r, c, new_mask);
+        while removed != 0 {
+            let bit = removed & removed.wrapping_neg();
+            let candidate = bit.trailing_zeros() as u8 + 1;
+            path.push(SolveStep::Eliminated { r, c, candidate, technique });
+            removed &= !bit;
+        }
     }
 
     /// Helper to remove a number and update caches.
@@ -84,18 +400,17 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Applies the naked singles technique.
-    fn naked_singles(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    fn naked_singles(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut placements_made = false;
-        let mut pass_placements: Vec<(usize, usize, u8)> = Vec::new();
+        let size = self.board.size();
 
-        for r in 0..9 {
-            for c in 0..9 {
+        for r in 0..size {
+            for c in 0..size {
                 if self.board.is_empty(r, c) {
                     let cand_mask = self.candidates_cache.get(r, c);
                     if cand_mask.count_ones() == 1 {
                         let num = cand_mask.trailing_zeros() as u8 + 1;
-                        self.place_and_update(r, c, num, path);
-                        pass_placements.push((r, c, num)); // Store for propagation
+                        self.place_and_update(r, c, num, path, Technique::NakedSingle);
                         placements_made = true;
                     }
                 }
@@ -105,12 +420,14 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Applies the hidden singles technique.
-    fn hidden_singles(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    fn hidden_singles(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut overall_placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
 
         let mut check_unit_hidden_singles = |unit_cells: &[(usize, usize)]| {
             let mut unit_placement_made = false;
-            for cand_val in 1..=9 {
+            for cand_val in 1..=size {
                 let cand_bit = 1 << (cand_val - 1);
                 let mut potential_cell: Option<(usize, usize)> = None;
                 let mut cand_occurrences = 0;
@@ -128,7 +445,13 @@ impl<'a> TechniquePropagator<'a> {
                 if cand_occurrences == 1 {
                     if let Some((r, c)) = potential_cell {
                         if self.board.is_empty(r, c) {
-                            self.place_and_update(r, c, cand_val, path);
+                            self.place_and_update(
+                                r,
+                                c,
+                                cand_val as u8,
+                                path,
+                                Technique::HiddenSingle,
+                            );
                             unit_placement_made = true;
                         }
                     }
@@ -137,30 +460,22 @@ impl<'a> TechniquePropagator<'a> {
             unit_placement_made
         };
 
-        for r in 0..9 {
-            let row_cells: Vec<(usize, usize)> = (0..9).map(|c| (r, c)).collect();
+        for r in 0..size {
+            let row_cells: Vec<(usize, usize)> = (0..size).map(|c| (r, c)).collect();
             if check_unit_hidden_singles(&row_cells) {
                 overall_placements_made = true;
             }
         }
 
-        for c in 0..9 {
-            let col_cells: Vec<(usize, usize)> = (0..9).map(|r| (r, c)).collect();
+        for c in 0..size {
+            let col_cells: Vec<(usize, usize)> = (0..size).map(|r| (r, c)).collect();
             if check_unit_hidden_singles(&col_cells) {
                 overall_placements_made = true;
             }
         }
 
-        for box_idx in 0..9 {
-            let mut box_cells: Vec<(usize, usize)> = Vec::with_capacity(9);
-            let start_row = (box_idx / 3) * 3;
-            let start_col = (box_idx % 3) * 3;
-            for r_offset in 0..3 {
-                for c_offset in 0..3 {
-                    box_cells.push((start_row + r_offset, start_col + c_offset));
-                }
-            }
-            if check_unit_hidden_singles(&box_cells) {
+        for box_idx in 0..size {
+            if check_unit_hidden_singles(&box_cells(order, box_idx)) {
                 overall_placements_made = true;
             }
         }
@@ -168,36 +483,30 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Applies the naked pairs technique.
-    fn naked_pairs(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    fn naked_pairs(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut overall_placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
 
         // Process rows
-        for i in 0..9 {
-            let row_cells: Vec<(usize, usize)> = (0..9).map(|col| (i, col)).collect();
+        for i in 0..size {
+            let row_cells: Vec<(usize, usize)> = (0..size).map(|col| (i, col)).collect();
             if self.process_unit_for_naked_pairs(&row_cells, path) {
                 overall_placements_made = true;
             }
         }
 
         // Process columns
-        for i in 0..9 {
-            let col_cells: Vec<(usize, usize)> = (0..9).map(|row| (row, i)).collect();
+        for i in 0..size {
+            let col_cells: Vec<(usize, usize)> = (0..size).map(|row| (row, i)).collect();
             if self.process_unit_for_naked_pairs(&col_cells, path) {
                 overall_placements_made = true;
             }
         }
 
-        // Process 3x3 boxes
-        for i in 0..9 {
-            let mut box_cells: Vec<(usize, usize)> = Vec::with_capacity(9);
-            let start_row = (i / 3) * 3;
-            let start_col = (i % 3) * 3;
-            for r_offset in 0..3 {
-                for c_offset in 0..3 {
-                    box_cells.push((start_row + r_offset, start_col + c_offset));
-                }
-            }
-            if self.process_unit_for_naked_pairs(&box_cells, path) {
+        // Process boxes
+        for i in 0..size {
+            if self.process_unit_for_naked_pairs(&box_cells(order, i), path) {
                 overall_placements_made = true;
             }
         }
@@ -208,10 +517,10 @@ impl<'a> TechniquePropagator<'a> {
     fn process_unit_for_naked_pairs(
         &mut self,
         unit_cells: &[(usize, usize)],
-        path: &mut Vec<(usize, usize, u8)>,
+        path: &mut Vec<SolveStep>,
     ) -> bool {
         let mut unit_placements_made = false;
-        let mut two_cand_cells: Vec<(usize, usize, u16)> = Vec::new();
+        let mut two_cand_cells: Vec<(usize, usize, u32)> = Vec::new();
 
         for &(r, c) in unit_cells {
             if self.board.is_empty(r, c) {
@@ -245,14 +554,26 @@ impl<'a> TechniquePropagator<'a> {
                             if (initial_mask & pair_cand_mask) != 0 {
                                 let refined_mask = initial_mask & !pair_cand_mask;
 
-                                self.candidates_cache.set(other_r, other_c, refined_mask);
+                                self.eliminate_candidates(
+                                    other_r,
+                                    other_c,
+                                    refined_mask,
+                                    path,
+                                    Technique::NakedPair,
+                                );
                                 unit_placements_made = true;
 
                                 if refined_mask.count_ones() == 1 {
                                     let num = refined_mask.trailing_zeros() as u8 + 1;
 
                                     if self.masks.is_safe(other_r, other_c, num) {
-                                        self.place_and_update(other_r, other_c, num, path);
+                                        self.place_and_update(
+                                            other_r,
+                                            other_c,
+                                            num,
+                                            path,
+                                            Technique::NakedPair,
+                                        );
                                     }
                                 }
                             }
@@ -264,37 +585,125 @@ impl<'a> TechniquePropagator<'a> {
         unit_placements_made
     }
 
+    /// Applies the naked subsets technique: naked triples (`k == 3`) and naked quads (`k == 4`).
+    /// Generalizes naked pairs to `k` cells within a unit whose candidates collectively span only
+    /// `k` digits.
+    fn naked_subsets(&mut self, path: &mut Vec<SolveStep>, k: usize) -> bool {
+        let mut overall_placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
+
+        // Process rows
+        for i in 0..size {
+            let row_cells: Vec<(usize, usize)> = (0..size).map(|col| (i, col)).collect();
+            if self.process_unit_for_naked_subsets(&row_cells, k, path) {
+                overall_placements_made = true;
+            }
+        }
+
+        // Process columns
+        for i in 0..size {
+            let col_cells: Vec<(usize, usize)> = (0..size).map(|row| (row, i)).collect();
+            if self.process_unit_for_naked_subsets(&col_cells, k, path) {
+                overall_placements_made = true;
+            }
+        }
+
+        // Process boxes
+        for i in 0..size {
+            if self.process_unit_for_naked_subsets(&box_cells(order, i), k, path) {
+                overall_placements_made = true;
+            }
+        }
+        overall_placements_made
+    }
+
+    /// Helper function to process a single unit (row, column, or box) for naked subsets of size
+    /// `k`: for every combination of `k` empty cells (each holding between 2 and `k` candidates)
+    /// whose candidates collectively span exactly `k` digits, those digits are eliminated from
+    /// every other cell in the unit.
+    fn process_unit_for_naked_subsets(
+        &mut self,
+        unit_cells: &[(usize, usize)],
+        k: usize,
+        path: &mut Vec<SolveStep>,
+    ) -> bool {
+        let mut unit_placements_made = false;
+
+        let candidate_cell_indices: Vec<usize> = (0..unit_cells.len())
+            .filter(|&idx| {
+                let (r, c) = unit_cells[idx];
+                if !self.board.is_empty(r, c) {
+                    return false;
+                }
+                let count = self.candidates_cache.get(r, c).count_ones() as usize;
+                (2..=k).contains(&count)
+            })
+            .collect();
+
+        for combo in combinations(&candidate_cell_indices, k) {
+            let combo_mask: u32 = combo.iter().fold(0, |mask, &idx| {
+                let (r, c) = unit_cells[idx];
+                mask | self.candidates_cache.get(r, c)
+            });
+
+            if combo_mask.count_ones() as usize != k {
+                continue;
+            }
+
+            for &(other_r, other_c) in unit_cells {
+                if combo.iter().any(|&idx| unit_cells[idx] == (other_r, other_c)) {
+                    continue;
+                }
+                if !self.board.is_empty(other_r, other_c) {
+                    continue;
+                }
+
+                let current_mask = self.candidates_cache.get(other_r, other_c);
+                if (current_mask & combo_mask) == 0 {
+                    continue;
+                }
+
+                let new_mask = current_mask & !combo_mask;
+                self.eliminate_candidates(other_r, other_c, new_mask, path, Technique::NakedSubset);
+                unit_placements_made = true;
+
+                if new_mask.count_ones() == 1 {
+                    let num = new_mask.trailing_zeros() as u8 + 1;
+                    if self.masks.is_safe(other_r, other_c, num) {
+                        self.place_and_update(other_r, other_c, num, path, Technique::NakedSubset);
+                    }
+                }
+            }
+        }
+        unit_placements_made
+    }
+
     /// Applies the hidden pairs technique.
-    fn hidden_pairs(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    fn hidden_pairs(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut overall_placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
 
         // Process rows
-        for i in 0..9 {
-            let row_cells: Vec<(usize, usize)> = (0..9).map(|col| (i, col)).collect();
+        for i in 0..size {
+            let row_cells: Vec<(usize, usize)> = (0..size).map(|col| (i, col)).collect();
             if self.process_unit_for_hidden_pairs(&row_cells, path) {
                 overall_placements_made = true;
             }
         }
 
         // Process columns
-        for i in 0..9 {
-            let col_cells: Vec<(usize, usize)> = (0..9).map(|row| (row, i)).collect();
+        for i in 0..size {
+            let col_cells: Vec<(usize, usize)> = (0..size).map(|row| (row, i)).collect();
             if self.process_unit_for_hidden_pairs(&col_cells, path) {
                 overall_placements_made = true;
             }
         }
 
-        // Process 3x3 boxes
-        for i in 0..9 {
-            let mut box_cells: Vec<(usize, usize)> = Vec::with_capacity(9);
-            let start_row = (i / 3) * 3;
-            let start_col = (i % 3) * 3;
-            for r_offset in 0..3 {
-                for c_offset in 0..3 {
-                    box_cells.push((start_row + r_offset, start_col + c_offset));
-                }
-            }
-            if self.process_unit_for_hidden_pairs(&box_cells, path) {
+        // Process boxes
+        for i in 0..size {
+            if self.process_unit_for_hidden_pairs(&box_cells(order, i), path) {
                 overall_placements_made = true;
             }
         }
@@ -305,12 +714,13 @@ impl<'a> TechniquePropagator<'a> {
     fn process_unit_for_hidden_pairs(
         &mut self,
         unit_cells: &[(usize, usize)],
-        path: &mut Vec<(usize, usize, u8)>,
+        path: &mut Vec<SolveStep>,
     ) -> bool {
         let mut unit_placements_made = false;
+        let size = self.board.size();
 
-        for n1_val in 1..=9 {
-            for n2_val in (n1_val + 1)..=9 {
+        for n1_val in 1..=size {
+            for n2_val in (n1_val + 1)..=size {
                 let n1_bit = 1 << (n1_val - 1);
                 let n2_bit = 1 << (n2_val - 1);
                 let pair_mask = n1_bit | n2_bit;
@@ -342,12 +752,12 @@ impl<'a> TechniquePropagator<'a> {
                     let new_mask1 = pair_mask;
 
                     if new_mask1 != current_mask1 {
-                        self.candidates_cache.set(r1, c1, new_mask1);
+                        self.eliminate_candidates(r1, c1, new_mask1, path, Technique::HiddenPair);
                         unit_placements_made = true;
                         if new_mask1.count_ones() == 1 {
                             let num = new_mask1.trailing_zeros() as u8 + 1;
                             if self.masks.is_safe(r1, c1, num) {
-                                self.place_and_update(r1, c1, num, path);
+                                self.place_and_update(r1, c1, num, path, Technique::HiddenPair);
                             }
                         }
                     }
@@ -356,12 +766,12 @@ impl<'a> TechniquePropagator<'a> {
                     let new_mask2 = pair_mask;
 
                     if new_mask2 != current_mask2 {
-                        self.candidates_cache.set(r2, c2, new_mask2);
+                        self.eliminate_candidates(r2, c2, new_mask2, path, Technique::HiddenPair);
                         unit_placements_made = true;
                         if new_mask2.count_ones() == 1 {
                             let num = new_mask2.trailing_zeros() as u8 + 1;
                             if self.masks.is_safe(r2, c2, num) {
-                                self.place_and_update(r2, c2, num, path);
+                                self.place_and_update(r2, c2, num, path, Technique::HiddenPair);
                             }
                         }
                     }
@@ -371,22 +781,125 @@ impl<'a> TechniquePropagator<'a> {
         unit_placements_made
     }
 
-    /// Applies the locked candidates technique.
-    fn locked_candidates(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    /// Applies the hidden subsets technique: hidden triples (`k == 3`) and hidden quads
+    /// (`k == 4`). Generalizes hidden pairs to `k` candidate values confined to `k` cells within a
+    /// unit.
+    fn hidden_subsets(&mut self, path: &mut Vec<SolveStep>, k: usize) -> bool {
+        let mut overall_placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
+
+        // Process rows
+        for i in 0..size {
+            let row_cells: Vec<(usize, usize)> = (0..size).map(|col| (i, col)).collect();
+            if self.process_unit_for_hidden_subsets(&row_cells, k, path) {
+                overall_placements_made = true;
+            }
+        }
+
+        // Process columns
+        for i in 0..size {
+            let col_cells: Vec<(usize, usize)> = (0..size).map(|row| (row, i)).collect();
+            if self.process_unit_for_hidden_subsets(&col_cells, k, path) {
+                overall_placements_made = true;
+            }
+        }
+
+        // Process boxes
+        for i in 0..size {
+            if self.process_unit_for_hidden_subsets(&box_cells(order, i), k, path) {
+                overall_placements_made = true;
+            }
+        }
+        overall_placements_made
+    }
+
+    /// Helper function to process a single unit (row, column, or box) for hidden subsets of size
+    /// `k`: for every combination of `k` still-missing values, the unit's empty cells containing
+    /// at least one of them form a hidden subset if there are exactly `k` such cells, in which
+    /// case every candidate outside the combination is eliminated from each of those cells. Skips
+    /// combinations whose containing-cell set is smaller than `k`, which would be a contradiction.
+    fn process_unit_for_hidden_subsets(
+        &mut self,
+        unit_cells: &[(usize, usize)],
+        k: usize,
+        path: &mut Vec<SolveStep>,
+    ) -> bool {
+        let mut unit_placements_made = false;
+        // Only values not already placed somewhere in the unit are eligible: an already-placed
+        // digit can't be a candidate anywhere else in the unit, so including it would shrink
+        // `containing_cells` for unrelated reasons and could spuriously match `k`, producing a
+        // "hidden subset" that isn't one.
+        let placed_mask: u32 = unit_cells.iter().fold(0, |mask, &(r, c)| {
+            let value = self.board.get(r, c);
+            if value == 0 {
+                mask
+            } else {
+                mask | (1 << (value - 1))
+            }
+        });
+        let values: Vec<usize> = (1..=self.board.size())
+            .filter(|&val| placed_mask & (1 << (val - 1)) == 0)
+            .collect();
+
+        for combo in combinations(&values, k) {
+            let combo_mask: u32 = combo.iter().fold(0, |mask, &val| mask | (1 << (val - 1)));
+
+            let containing_cells: Vec<(usize, usize)> = unit_cells
+                .iter()
+                .copied()
+                .filter(|&(r, c)| {
+                    self.board.is_empty(r, c)
+                        && (self.candidates_cache.get(r, c) & combo_mask) != 0
+                })
+                .collect();
+
+            if containing_cells.len() != k {
+                continue;
+            }
+
+            for (r, c) in containing_cells {
+                let current_mask = self.candidates_cache.get(r, c);
+                let new_mask = current_mask & combo_mask;
+                if new_mask != current_mask {
+                    self.eliminate_candidates(r, c, new_mask, path, Technique::HiddenSubset);
+                    unit_placements_made = true;
+                    if new_mask.count_ones() == 1 {
+                        let num = new_mask.trailing_zeros() as u8 + 1;
+                        if self.masks.is_safe(r, c, num) {
+                            self.place_and_update(r, c, num, path, Technique::HiddenSubset);
+                        }
+                    }
+                }
+            }
+        }
+        unit_placements_made
+    }
+
+    /// Applies the locked candidates technique's pointing direction: a candidate confined to one
+    /// box within a row or column is eliminated from the rest of that box.
+    fn locked_candidates_pointing(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut overall_placements_made = false;
+        let size = self.board.size();
 
-        // Check rows for pointing pairs/triples
-        for row in 0..9 {
+        for row in 0..size {
             overall_placements_made |= self.process_row_for_locked_candidates(row, path);
         }
 
-        // Check columns for pointing pairs/triples
-        for col in 0..9 {
+        for col in 0..size {
             overall_placements_made |= self.process_col_for_locked_candidates(col, path);
         }
 
-        // Check boxes for box/line reduction
-        for box_idx in 0..9 {
+        overall_placements_made
+    }
+
+    /// Applies the locked candidates technique's claiming direction: a candidate confined to one
+    /// row or column within a box is eliminated from the rest of that row/column.
+    fn locked_candidates_claiming(&mut self, path: &mut Vec<SolveStep>) -> bool {
+        let mut overall_placements_made = false;
+        let size = self.board.size();
+
+        for box_idx in 0..size {
             overall_placements_made |= self.process_box_for_locked_candidates(box_idx, path);
         }
 
@@ -394,18 +907,16 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Helper function to process a row for locked candidates (pointing pairs/triples).
-    fn process_row_for_locked_candidates(
-        &mut self,
-        row: usize,
-        path: &mut Vec<(usize, usize, u8)>,
-    ) -> bool {
+    fn process_row_for_locked_candidates(&mut self, row: usize, path: &mut Vec<SolveStep>) -> bool {
         let mut placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
 
-        for candidate in 1..=9 {
+        for candidate in 1..=size {
             let candidate_bit = 1 << (candidate - 1);
 
             // Find cells in the row that contain the candidate
-            let candidate_cells: Vec<usize> = (0..9)
+            let candidate_cells: Vec<usize> = (0..size)
                 .filter(|&col| {
                     self.board.is_empty(row, col)
                         && (self.candidates_cache.get(row, col) & candidate_bit) != 0
@@ -415,28 +926,40 @@ impl<'a> TechniquePropagator<'a> {
             // If the candidate only appears in one box, it's a pointing pair/triple
             let boxes: std::collections::HashSet<usize> = candidate_cells
                 .iter()
-                .map(|&col| (row / 3) * 3 + (col / 3))
+                .map(|&col| (row / order) * order + (col / order))
                 .collect();
 
             if boxes.len() == 1 {
                 let box_idx = *boxes.iter().next().unwrap();
-                let start_row = (box_idx / 3) * 3;
-                let start_col = (box_idx % 3) * 3;
+                let start_row = (box_idx / order) * order;
+                let start_col = (box_idx % order) * order;
 
                 // Remove the candidate from other cells in the box, but not in the row
-                for r in start_row..(start_row + 3) {
-                    for c in start_col..(start_col + 3) {
+                for r in start_row..(start_row + order) {
+                    for c in start_col..(start_col + order) {
                         if r != row && self.board.is_empty(r, c) {
                             let initial_mask = self.candidates_cache.get(r, c);
                             if (initial_mask & candidate_bit) != 0 {
                                 let refined_mask = initial_mask & !candidate_bit;
-                                self.candidates_cache.set(r, c, refined_mask);
+                                self.eliminate_candidates(
+                                    r,
+                                    c,
+                                    refined_mask,
+                                    path,
+                                    Technique::LockedCandidatesPointing,
+                                );
                                 placements_made = true;
 
                                 if refined_mask.count_ones() == 1 {
                                     let num = refined_mask.trailing_zeros() as u8 + 1;
                                     if self.masks.is_safe(r, c, num) {
-                                        self.place_and_update(r, c, num, path);
+                                        self.place_and_update(
+                                            r,
+                                            c,
+                                            num,
+                                            path,
+                                            Technique::LockedCandidatesPointing,
+                                        );
                                     }
                                 }
                             }
@@ -450,18 +973,16 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Helper function to process a column for locked candidates (pointing pairs/triples).
-    fn process_col_for_locked_candidates(
-        &mut self,
-        col: usize,
-        path: &mut Vec<(usize, usize, u8)>,
-    ) -> bool {
+    fn process_col_for_locked_candidates(&mut self, col: usize, path: &mut Vec<SolveStep>) -> bool {
         let mut placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
 
-        for candidate in 1..=9 {
+        for candidate in 1..=size {
             let candidate_bit = 1 << (candidate - 1);
 
             // Find cells in the column that contain the candidate
-            let candidate_cells: Vec<usize> = (0..9)
+            let candidate_cells: Vec<usize> = (0..size)
                 .filter(|&row| {
                     self.board.is_empty(row, col)
                         && (self.candidates_cache.get(row, col) & candidate_bit) != 0
@@ -471,28 +992,40 @@ impl<'a> TechniquePropagator<'a> {
             // If the candidate only appears in one box, it's a pointing pair/triple
             let boxes: std::collections::HashSet<usize> = candidate_cells
                 .iter()
-                .map(|&row| (row / 3) * 3 + (col / 3))
+                .map(|&row| (row / order) * order + (col / order))
                 .collect();
 
             if boxes.len() == 1 {
                 let box_idx = *boxes.iter().next().unwrap();
-                let start_row = (box_idx / 3) * 3;
-                let start_col = (box_idx % 3) * 3;
+                let start_row = (box_idx / order) * order;
+                let start_col = (box_idx % order) * order;
 
                 // Remove the candidate from other cells in the box, but not in the column
-                for r in start_row..(start_row + 3) {
-                    for c in start_col..(start_col + 3) {
+                for r in start_row..(start_row + order) {
+                    for c in start_col..(start_col + order) {
                         if c != col && self.board.is_empty(r, c) {
                             let initial_mask = self.candidates_cache.get(r, c);
                             if (initial_mask & candidate_bit) != 0 {
                                 let refined_mask = initial_mask & !candidate_bit;
-                                self.candidates_cache.set(r, c, refined_mask);
+                                self.eliminate_candidates(
+                                    r,
+                                    c,
+                                    refined_mask,
+                                    path,
+                                    Technique::LockedCandidatesPointing,
+                                );
                                 placements_made = true;
 
                                 if refined_mask.count_ones() == 1 {
                                     let num = refined_mask.trailing_zeros() as u8 + 1;
                                     if self.masks.is_safe(r, c, num) {
-                                        self.place_and_update(r, c, num, path);
+                                        self.place_and_update(
+                                            r,
+                                            c,
+                                            num,
+                                            path,
+                                            Technique::LockedCandidatesPointing,
+                                        );
                                     }
                                 }
                             }
@@ -509,19 +1042,21 @@ impl<'a> TechniquePropagator<'a> {
     fn process_box_for_locked_candidates(
         &mut self,
         box_idx: usize,
-        path: &mut Vec<(usize, usize, u8)>,
+        path: &mut Vec<SolveStep>,
     ) -> bool {
         let mut placements_made = false;
-        let start_row = (box_idx / 3) * 3;
-        let start_col = (box_idx % 3) * 3;
+        let size = self.board.size();
+        let order = self.board.order;
+        let start_row = (box_idx / order) * order;
+        let start_col = (box_idx % order) * order;
 
-        for candidate in 1..=9 {
+        for candidate in 1..=size {
             let candidate_bit = 1 << (candidate - 1);
 
             // Find cells in the box that contain the candidate
             let mut candidate_cells: Vec<(usize, usize)> = Vec::new();
-            for r_offset in 0..3 {
-                for c_offset in 0..3 {
+            for r_offset in 0..order {
+                for c_offset in 0..order {
                     let r = start_row + r_offset;
                     let c = start_col + c_offset;
                     if self.board.is_empty(r, c)
@@ -539,18 +1074,30 @@ impl<'a> TechniquePropagator<'a> {
                 let row = *rows.iter().next().unwrap();
 
                 // Remove candidate from other cells in the row, but not in the box
-                for c in 0..9 {
-                    if (c < start_col || c >= start_col + 3) && self.board.is_empty(row, c) {
+                for c in 0..size {
+                    if (c < start_col || c >= start_col + order) && self.board.is_empty(row, c) {
                         let initial_mask = self.candidates_cache.get(row, c);
                         if (initial_mask & candidate_bit) != 0 {
                             let refined_mask = initial_mask & !candidate_bit;
-                            self.candidates_cache.set(row, c, refined_mask);
+                            self.eliminate_candidates(
+                                row,
+                                c,
+                                refined_mask,
+                                path,
+                                Technique::LockedCandidatesClaiming,
+                            );
                             placements_made = true;
 
                             if refined_mask.count_ones() == 1 {
                                 let num = refined_mask.trailing_zeros() as u8 + 1;
                                 if self.masks.is_safe(row, c, num) {
-                                    self.place_and_update(row, c, num, path);
+                                    self.place_and_update(
+                                        row,
+                                        c,
+                                        num,
+                                        path,
+                                        Technique::LockedCandidatesClaiming,
+                                    );
                                 }
                             }
                         }
@@ -565,18 +1112,30 @@ impl<'a> TechniquePropagator<'a> {
                 let col = *cols.iter().next().unwrap();
 
                 // Remove candidate from other cells in the column, but not in the box
-                for r in 0..9 {
-                    if (r < start_row || r >= start_row + 3) && self.board.is_empty(r, col) {
+                for r in 0..size {
+                    if (r < start_row || r >= start_row + order) && self.board.is_empty(r, col) {
                         let initial_mask = self.candidates_cache.get(r, col);
                         if (initial_mask & candidate_bit) != 0 {
                             let refined_mask = initial_mask & !candidate_bit;
-                            self.candidates_cache.set(r, col, refined_mask);
+                            self.eliminate_candidates(
+                                r,
+                                col,
+                                refined_mask,
+                                path,
+                                Technique::LockedCandidatesClaiming,
+                            );
                             placements_made = true;
 
                             if refined_mask.count_ones() == 1 {
                                 let num = refined_mask.trailing_zeros() as u8 + 1;
                                 if self.masks.is_safe(r, col, num) {
-                                    self.place_and_update(r, col, num, path);
+                                    self.place_and_update(
+                                        r,
+                                        col,
+                                        num,
+                                        path,
+                                        Technique::LockedCandidatesClaiming,
+                                    );
                                 }
                             }
                         }
@@ -589,19 +1148,21 @@ impl<'a> TechniquePropagator<'a> {
     }
 
     /// Applies the X-Wing technique.
-    fn x_wing(&mut self, path: &mut Vec<(usize, usize, u8)>) -> bool {
+    #[cfg(not(feature = "rayon"))]
+    fn x_wing(&mut self, path: &mut Vec<SolveStep>) -> bool {
         let mut placements_made = false;
+        let size = self.board.size();
 
-        for candidate_val in 1..=9 {
+        for candidate_val in 1..=size {
             let candidate_bit = 1 << (candidate_val - 1);
 
             // Check for row-based X-Wings
             let mut rows_with_two_candidates: Vec<usize> = Vec::new();
             let mut candidate_cols_in_rows: Vec<Vec<usize>> = Vec::new();
 
-            for r in 0..9 {
+            for r in 0..size {
                 let mut cols_for_candidate_in_row: Vec<usize> = Vec::new();
-                for c in 0..9 {
+                for c in 0..size {
                     if self.board.is_empty(r, c)
                         && (self.candidates_cache.get(r, c) & candidate_bit) != 0
                     {
@@ -627,17 +1188,29 @@ impl<'a> TechniquePropagator<'a> {
 
                         // Found an X-Wing in columns c1 and c2 across rows r1 and r2
                         // Remove candidate from other cells in column c1 (excluding r1, r2)
-                        for r_other in 0..9 {
+                        for r_other in 0..size {
                             if r_other != r1 && r_other != r2 && self.board.is_empty(r_other, c1) {
                                 let initial_mask = self.candidates_cache.get(r_other, c1);
                                 if (initial_mask & candidate_bit) != 0 {
                                     let refined_mask = initial_mask & !candidate_bit;
-                                    self.candidates_cache.set(r_other, c1, refined_mask);
+                                    self.eliminate_candidates(
+                                        r_other,
+                                        c1,
+                                        refined_mask,
+                                        path,
+                                        Technique::XWing,
+                                    );
                                     placements_made = true;
                                     if refined_mask.count_ones() == 1 {
                                         let num = refined_mask.trailing_zeros() as u8 + 1;
                                         if self.masks.is_safe(r_other, c1, num) {
-                                            self.place_and_update(r_other, c1, num, path);
+                                            self.place_and_update(
+                                                r_other,
+                                                c1,
+                                                num,
+                                                path,
+                                                Technique::XWing,
+                                            );
                                         }
                                     }
                                 }
@@ -645,17 +1218,29 @@ impl<'a> TechniquePropagator<'a> {
                         }
 
                         // Remove candidate from other cells in column c2 (excluding r1, r2)
-                        for r_other in 0..9 {
+                        for r_other in 0..size {
                             if r_other != r1 && r_other != r2 && self.board.is_empty(r_other, c2) {
                                 let initial_mask = self.candidates_cache.get(r_other, c2);
                                 if (initial_mask & candidate_bit) != 0 {
                                     let refined_mask = initial_mask & !candidate_bit;
-                                    self.candidates_cache.set(r_other, c2, refined_mask);
+                                    self.eliminate_candidates(
+                                        r_other,
+                                        c2,
+                                        refined_mask,
+                                        path,
+                                        Technique::XWing,
+                                    );
                                     placements_made = true;
                                     if refined_mask.count_ones() == 1 {
                                         let num = refined_mask.trailing_zeros() as u8 + 1;
                                         if self.masks.is_safe(r_other, c2, num) {
-                                            self.place_and_update(r_other, c2, num, path);
+                                            self.place_and_update(
+                                                r_other,
+                                                c2,
+                                                num,
+                                                path,
+                                                Technique::XWing,
+                                            );
                                         }
                                     }
                                 }
@@ -669,9 +1254,9 @@ impl<'a> TechniquePropagator<'a> {
             let mut cols_with_two_candidates: Vec<usize> = Vec::new();
             let mut candidate_rows_in_cols: Vec<Vec<usize>> = Vec::new();
 
-            for c in 0..9 {
+            for c in 0..size {
                 let mut rows_for_candidate_in_col: Vec<usize> = Vec::new();
-                for r in 0..9 {
+                for r in 0..size {
                     if self.board.is_empty(r, c)
                         && (self.candidates_cache.get(r, c) & candidate_bit) != 0
                     {
@@ -697,17 +1282,29 @@ impl<'a> TechniquePropagator<'a> {
 
                         // Found an X-Wing in rows r1 and r2 across columns c1 and c2
                         // Remove candidate from other cells in row r1 (excluding c1, c2)
-                        for c_other in 0..9 {
+                        for c_other in 0..size {
                             if c_other != c1 && c_other != c2 && self.board.is_empty(r1, c_other) {
                                 let initial_mask = self.candidates_cache.get(r1, c_other);
                                 if (initial_mask & candidate_bit) != 0 {
                                     let refined_mask = initial_mask & !candidate_bit;
-                                    self.candidates_cache.set(r1, c_other, refined_mask);
+                                    self.eliminate_candidates(
+                                        r1,
+                                        c_other,
+                                        refined_mask,
+                                        path,
+                                        Technique::XWing,
+                                    );
                                     placements_made = true;
                                     if refined_mask.count_ones() == 1 {
                                         let num = refined_mask.trailing_zeros() as u8 + 1;
                                         if self.masks.is_safe(r1, c_other, num) {
-                                            self.place_and_update(r1, c_other, num, path);
+                                            self.place_and_update(
+                                                r1,
+                                                c_other,
+                                                num,
+                                                path,
+                                                Technique::XWing,
+                                            );
                                         }
                                     }
                                 }
@@ -715,17 +1312,29 @@ impl<'a> TechniquePropagator<'a> {
                         }
 
                         // Remove candidate from other cells in row r2 (excluding c1, c2)
-                        for c_other in 0..9 {
+                        for c_other in 0..size {
                             if c_other != c1 && c_other != c2 && self.board.is_empty(r2, c_other) {
                                 let initial_mask = self.candidates_cache.get(r2, c_other);
                                 if (initial_mask & candidate_bit) != 0 {
                                     let refined_mask = initial_mask & !candidate_bit;
-                                    self.candidates_cache.set(r2, c_other, refined_mask);
+                                    self.eliminate_candidates(
+                                        r2,
+                                        c_other,
+                                        refined_mask,
+                                        path,
+                                        Technique::XWing,
+                                    );
                                     placements_made = true;
                                     if refined_mask.count_ones() == 1 {
                                         let num = refined_mask.trailing_zeros() as u8 + 1;
                                         if self.masks.is_safe(r2, c_other, num) {
-                                            self.place_and_update(r2, c_other, num, path);
+                                            self.place_and_update(
+                                                r2,
+                                                c_other,
+                                                num,
+                                                path,
+                                                Technique::XWing,
+                                            );
                                         }
                                     }
                                 }
@@ -738,10 +1347,425 @@ impl<'a> TechniquePropagator<'a> {
         placements_made
     }
 
+    /// Applies the X-Wing technique, computing eliminations for each digit of the board in
+    /// parallel with Rayon and applying them serially afterward to avoid mutably aliasing the
+    /// shared candidates cache.
+    #[cfg(feature = "rayon")]
+    fn x_wing(&mut self, path: &mut Vec<SolveStep>) -> bool {
+        use rayon::prelude::*;
+
+        let board = self.board.clone();
+        let size = board.size();
+        let candidates_cache = self.candidates_cache.clone();
+
+        let eliminations: Vec<(usize, usize, u8)> = (1..=size as u8)
+            .into_par_iter()
+            .flat_map(|candidate_val| {
+                x_wing_eliminations_for_digit(&board, &candidates_cache, candidate_val)
+            })
+            .collect();
+
+        let mut placements_made = false;
+        for (r, c, candidate_val) in eliminations {
+            if !self.board.is_empty(r, c) {
+                continue;
+            }
+            let candidate_bit: u32 = 1 << (candidate_val - 1);
+            let mask = self.candidates_cache.get(r, c);
+            if (mask & candidate_bit) == 0 {
+                continue;
+            }
+            let refined_mask = mask & !candidate_bit;
+            self.eliminate_candidates(r, c, refined_mask, path, Technique::XWing);
+            placements_made = true;
+            if refined_mask.count_ones() == 1 {
+                let num = refined_mask.trailing_zeros() as u8 + 1;
+                if self.masks.is_safe(r, c, num) {
+                    self.place_and_update(r, c, num, path, Technique::XWing);
+                }
+            }
+        }
+        placements_made
+    }
+
+    /// Applies the fish technique (Swordfish for `size == 3`, Jellyfish for `size == 4`): a
+    /// generalization of X-Wing (itself the `size == 2` case, handled separately by
+    /// [`Self::x_wing`] for historical reasons) where a candidate confined to `size` rows, each
+    /// within a common set of `size` columns (or the symmetric row/column swap), can be
+    /// eliminated from every other cell in those columns. One function handles both sizes so
+    /// Swordfish and Jellyfish never drift out of sync with each other.
+    fn fish(&mut self, path: &mut Vec<SolveStep>, size: usize) -> bool {
+        let mut placements_made = false;
+        let board_size = self.board.size() as u8;
+        let technique = match size {
+            3 => Technique::Swordfish,
+            _ => Technique::Jellyfish,
+        };
+
+        for candidate_val in 1..=board_size {
+            let candidate_bit: u32 = 1 << (candidate_val - 1);
+            placements_made |= self.fish_pass(path, candidate_bit, size, true, technique);
+            placements_made |= self.fish_pass(path, candidate_bit, size, false, technique);
+        }
+
+        placements_made
+    }
+
+    /// One direction of a [`fish`](Self::fish) pass: `rows_as_base` scans rows as the base lines
+    /// and columns as the cover lines; set to `false` for the symmetric column-base case.
+    fn fish_pass(
+        &mut self,
+        path: &mut Vec<SolveStep>,
+        candidate_bit: u32,
+        size: usize,
+        rows_as_base: bool,
+        technique: Technique,
+    ) -> bool {
+        let mut placements_made = false;
+        let board_size = self.board.size();
+
+        // line_masks[base] = bitmask of cross-line indices where the candidate is present.
+        let line_masks: Vec<u32> = (0..board_size)
+            .map(|base| {
+                (0..board_size).fold(0u32, |mask, cross| {
+                    let (r, c) = if rows_as_base { (base, cross) } else { (cross, base) };
+                    if self.board.is_empty(r, c) && (self.candidates_cache.get(r, c) & candidate_bit) != 0 {
+                        mask | (1 << cross)
+                    } else {
+                        mask
+                    }
+                })
+            })
+            .collect();
+
+        let candidate_lines: Vec<usize> = (0..board_size)
+            .filter(|&i| {
+                let count = line_masks[i].count_ones() as usize;
+                (2..=size).contains(&count)
+            })
+            .collect();
+
+        if candidate_lines.len() < size {
+            return false;
+        }
+
+        for combo in combinations(&candidate_lines, size) {
+            let union_mask = combo.iter().fold(0u32, |mask, &line| mask | line_masks[line]);
+            if union_mask.count_ones() as usize != size {
+                continue;
+            }
+
+            for cross in 0..board_size {
+                if union_mask & (1 << cross) == 0 {
+                    continue;
+                }
+                for base in 0..board_size {
+                    if combo.contains(&base) {
+                        continue;
+                    }
+                    let (r, c) = if rows_as_base { (base, cross) } else { (cross, base) };
+                    if !self.board.is_empty(r, c) {
+                        continue;
+                    }
+                    let mask = self.candidates_cache.get(r, c);
+                    if (mask & candidate_bit) == 0 {
+                        continue;
+                    }
+                    let refined = mask & !candidate_bit;
+                    self.eliminate_candidates(r, c, refined, path, technique);
+                    placements_made = true;
+                    if refined.count_ones() == 1 {
+                        let num = refined.trailing_zeros() as u8 + 1;
+                        if self.masks.is_safe(r, c, num) {
+                            self.place_and_update(r, c, num, path, technique);
+                        }
+                    }
+                }
+            }
+        }
+
+        placements_made
+    }
+
+    /// Applies the Simple Coloring (single-digit chaining) technique.
+    ///
+    /// For each candidate digit, builds a graph of the empty cells that can hold it, linking two
+    /// cells whenever the digit is a conjugate pair (its only two positions) in some unit. Each
+    /// connected component is bipartite by construction, so it can be two-colored by BFS; any
+    /// contradiction found between same-colored cells, or any outside cell that sees both
+    /// colors, eliminates the candidate.
+    fn simple_coloring(&mut self, path: &mut Vec<SolveStep>) -> bool {
+        let mut placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
+
+        for cand_val in 1..=size as u8 {
+            let cand_bit: u32 = 1 << (cand_val - 1);
+
+            let cells: Vec<(usize, usize)> = (0..size)
+                .flat_map(|r| (0..size).map(move |c| (r, c)))
+                .filter(|&(r, c)| {
+                    self.board.is_empty(r, c) && (self.candidates_cache.get(r, c) & cand_bit) != 0
+                })
+                .collect();
+
+            if cells.len() < 2 {
+                continue;
+            }
+
+            let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); cells.len()];
+            let link_unit = |unit: &[(usize, usize)], adjacency: &mut Vec<Vec<usize>>| {
+                let members: Vec<usize> = unit
+                    .iter()
+                    .filter_map(|cell| cells.iter().position(|&c| c == *cell))
+                    .collect();
+                if members.len() == 2 {
+                    adjacency[members[0]].push(members[1]);
+                    adjacency[members[1]].push(members[0]);
+                }
+            };
+
+            for r in 0..size {
+                let unit: Vec<(usize, usize)> = (0..size).map(|c| (r, c)).collect();
+                link_unit(&unit, &mut adjacency);
+            }
+            for c in 0..size {
+                let unit: Vec<(usize, usize)> = (0..size).map(|r| (r, c)).collect();
+                link_unit(&unit, &mut adjacency);
+            }
+            for box_idx in 0..size {
+                let start_row = (box_idx / order) * order;
+                let start_col = (box_idx % order) * order;
+                let unit: Vec<(usize, usize)> = (0..order)
+                    .flat_map(|ro| (0..order).map(move |co| (start_row + ro, start_col + co)))
+                    .collect();
+                link_unit(&unit, &mut adjacency);
+            }
+
+            // Two-color each connected component via BFS; the graph of strong links is
+            // bipartite, so alternating colors never conflicts within a component. Each
+            // component's true/false labels are chosen independently, so they only mean
+            // anything when compared against another cell of the *same* component.
+            let mut colors: Vec<Option<bool>> = vec![None; cells.len()];
+            let mut components: Vec<Option<usize>> = vec![None; cells.len()];
+            let mut next_component = 0usize;
+            for start in 0..cells.len() {
+                if colors[start].is_some() {
+                    continue;
+                }
+                colors[start] = Some(true);
+                components[start] = Some(next_component);
+                let mut queue = VecDeque::new();
+                queue.push_back(start);
+                while let Some(node) = queue.pop_front() {
+                    let node_color = colors[node].unwrap();
+                    for &neighbor in &adjacency[node] {
+                        if colors[neighbor].is_none() {
+                            colors[neighbor] = Some(!node_color);
+                            components[neighbor] = Some(next_component);
+                            queue.push_back(neighbor);
+                        }
+                    }
+                }
+                next_component += 1;
+            }
+
+            // Rule 1 (color trap): two same-colored cells of the same component sharing a
+            // unit make that color contradictory, so every cell of that color in that
+            // component loses the candidate.
+            let mut bad_colors: Vec<(usize, bool)> = Vec::new();
+            for i in 0..cells.len() {
+                for j in (i + 1)..cells.len() {
+                    if components[i] != components[j] {
+                        continue;
+                    }
+                    if colors[i] == colors[j] {
+                        if let Some(color) = colors[i] {
+                            let component = components[i].unwrap();
+                            if same_unit(cells[i], cells[j], order)
+                                && !bad_colors.contains(&(component, color))
+                            {
+                                bad_colors.push((component, color));
+                            }
+                        }
+                    }
+                }
+            }
+
+            if !bad_colors.is_empty() {
+                for (idx, &(r, c)) in cells.iter().enumerate() {
+                    let in_bad_color = colors[idx].is_some_and(|color| {
+                        let component = components[idx].unwrap();
+                        bad_colors.contains(&(component, color))
+                    });
+                    if in_bad_color && self.board.is_empty(r, c) {
+                        let mask = self.candidates_cache.get(r, c);
+                        if (mask & cand_bit) != 0 {
+                            let refined = mask & !cand_bit;
+                            self.eliminate_candidates(
+                                r,
+                                c,
+                                refined,
+                                path,
+                                Technique::SimpleColoring,
+                            );
+                            placements_made = true;
+                            if refined.count_ones() == 1 {
+                                let num = refined.trailing_zeros() as u8 + 1;
+                                if self.masks.is_safe(r, c, num) {
+                                    self.place_and_update(
+                                        r,
+                                        c,
+                                        num,
+                                        path,
+                                        Technique::SimpleColoring,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+                // The board changed, so the chain's candidate positions are now stale; let the
+                // next iteration of propagate_constraints rebuild the graph from scratch.
+                continue;
+            }
+
+            // Rule 2 (cell sees both colors): an outside cell that shares a unit with a
+            // color-A cell and a color-B cell of the *same component* can't be the candidate
+            // either way, so it loses it. Cross-component colors carry no relationship, so
+            // each component is checked independently.
+            for r in 0..size {
+                for c in 0..size {
+                    if !self.board.is_empty(r, c) || cells.contains(&(r, c)) {
+                        continue;
+                    }
+                    let mask = self.candidates_cache.get(r, c);
+                    if (mask & cand_bit) == 0 {
+                        continue;
+                    }
+
+                    let sees_both = (0..next_component).any(|component| {
+                        let sees_true = cells.iter().enumerate().any(|(idx, &cell)| {
+                            colors[idx] == Some(true)
+                                && components[idx] == Some(component)
+                                && same_unit(cell, (r, c), order)
+                        });
+                        let sees_false = cells.iter().enumerate().any(|(idx, &cell)| {
+                            colors[idx] == Some(false)
+                                && components[idx] == Some(component)
+                                && same_unit(cell, (r, c), order)
+                        });
+                        sees_true && sees_false
+                    });
+
+                    if sees_both {
+                        let refined = mask & !cand_bit;
+                        self.eliminate_candidates(r, c, refined, path, Technique::SimpleColoring);
+                        placements_made = true;
+                        if refined.count_ones() == 1 {
+                            let num = refined.trailing_zeros() as u8 + 1;
+                            if self.masks.is_safe(r, c, num) {
+                                self.place_and_update(r, c, num, path, Technique::SimpleColoring);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        placements_made
+    }
+
+    /// Applies the XY-Chain technique: a generalization of naked pairs into arbitrary-length
+    /// chains of bivalue cells.
+    ///
+    /// Builds an implicit graph over bivalue cells (candidate masks with exactly two bits),
+    /// where two cells are linked whenever they see each other and share a candidate. A chain
+    /// starting at cell `C1` with candidates `{z, a}` assumes `C1 ≠ z` (so `C1 = a`); that forces
+    /// a sees-linked neighbor off `a`, onto its other candidate, and so on, alternating along the
+    /// chain. If this alternation ever forces some cell `Ck` to equal `z` again, then `C1 = z` or
+    /// `Ck = z` must hold, so `z` can be eliminated from any other cell that sees both endpoints.
+    /// Chains are explored by DFS up to `MAX_CHAIN_LEN` cells to keep the search tractable.
+    fn xy_chain(&mut self, path: &mut Vec<SolveStep>) -> bool {
+        const MAX_CHAIN_LEN: usize = 8;
+        let mut placements_made = false;
+        let size = self.board.size();
+        let order = self.board.order;
+
+        let mut bivalue_cells: Vec<((usize, usize), [u8; 2])> = Vec::new();
+        for r in 0..size {
+            for c in 0..size {
+                if self.board.is_empty(r, c) {
+                    let mask = self.candidates_cache.get(r, c);
+                    if mask.count_ones() == 2 {
+                        let values: Vec<u8> =
+                            (1..=size as u8).filter(|&v| mask & (1 << (v - 1)) != 0).collect();
+                        bivalue_cells.push(((r, c), [values[0], values[1]]));
+                    }
+                }
+            }
+        }
+
+        if bivalue_cells.len() < 2 {
+            return false;
+        }
+
+        for start_idx in 0..bivalue_cells.len() {
+            let (start_cell, start_vals) = bivalue_cells[start_idx];
+            for &z in &start_vals {
+                let entry_forced = if start_vals[0] == z { start_vals[1] } else { start_vals[0] };
+
+                let mut visited = vec![false; bivalue_cells.len()];
+                visited[start_idx] = true;
+                let search = XyChainSearch {
+                    cells: &bivalue_cells,
+                    target: z,
+                    max_len: MAX_CHAIN_LEN,
+                    order,
+                };
+                let end_idx = search.find_end(start_idx, entry_forced, &mut visited, 1);
+
+                let Some(end_idx) = end_idx else {
+                    continue;
+                };
+                let end_cell = bivalue_cells[end_idx].0;
+                let z_bit: u32 = 1 << (z - 1);
+
+                for r in 0..size {
+                    for c in 0..size {
+                        if (r, c) == start_cell || (r, c) == end_cell || !self.board.is_empty(r, c)
+                        {
+                            continue;
+                        }
+                        if !same_unit(start_cell, (r, c), order) || !same_unit(end_cell, (r, c), order) {
+                            continue;
+                        }
+                        let mask = self.candidates_cache.get(r, c);
+                        if (mask & z_bit) == 0 {
+                            continue;
+                        }
+
+                        let refined = mask & !z_bit;
+                        self.eliminate_candidates(r, c, refined, path, Technique::XyChain);
+                        placements_made = true;
+                        if refined.count_ones() == 1 {
+                            let num = refined.trailing_zeros() as u8 + 1;
+                            if self.masks.is_safe(r, c, num) {
+                                self.place_and_update(r, c, num, path, Technique::XyChain);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        placements_made
+    }
+
     /// Applies deterministic constraint propagation techniques iteratively.
     pub(super) fn propagate_constraints(
         &mut self,
-        path: &mut Vec<(usize, usize, u8)>,
+        path: &mut Vec<SolveStep>,
         initial_path_len: usize,
     ) -> bool {
         loop {
@@ -777,22 +1801,91 @@ impl<'a> TechniquePropagator<'a> {
             if !changed_this_iter
                 && self
                     .techniques_enabled
-                    .contains(RustokuTechniques::LOCKED_CANDIDATES)
+                    .contains(RustokuTechniques::HIDDEN_SUBSETS)
+            {
+                changed_this_iter |= self.hidden_subsets(path, 3);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::HIDDEN_SUBSETS)
             {
-                changed_this_iter |= self.locked_candidates(path);
+                changed_this_iter |= self.hidden_subsets(path, 4);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::NAKED_SUBSETS)
+            {
+                changed_this_iter |= self.naked_subsets(path, 3);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::NAKED_SUBSETS)
+            {
+                changed_this_iter |= self.naked_subsets(path, 4);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::LOCKED_CANDIDATES_POINTING)
+            {
+                changed_this_iter |= self.locked_candidates_pointing(path);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::LOCKED_CANDIDATES_CLAIMING)
+            {
+                changed_this_iter |= self.locked_candidates_claiming(path);
             }
             if !changed_this_iter && self.techniques_enabled.contains(RustokuTechniques::X_WING) {
                 changed_this_iter |= self.x_wing(path);
             }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::SWORDFISH)
+            {
+                changed_this_iter |= self.fish(path, 3);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::JELLYFISH)
+            {
+                changed_this_iter |= self.fish(path, 4);
+            }
+            if !changed_this_iter
+                && self
+                    .techniques_enabled
+                    .contains(RustokuTechniques::SIMPLE_COLORING)
+            {
+                changed_this_iter |= self.simple_coloring(path);
+            }
+            if !changed_this_iter
+                && self.techniques_enabled.contains(RustokuTechniques::XY_CHAIN)
+            {
+                changed_this_iter |= self.xy_chain(path);
+            }
 
             // Contradiction check
-            if (0..9).any(|r| {
-                (0..9).any(|c| self.board.is_empty(r, c) && self.candidates_cache.get(r, c) == 0)
+            let size = self.board.size();
+            if (0..size).any(|r| {
+                (0..size).any(|c| self.board.is_empty(r, c) && self.candidates_cache.get(r, c) == 0)
             }) {
-                // Contradiction: Roll back placements from this propagation call
+                // Contradiction: roll back placements and eliminations from this propagation call
                 while path.len() > initial_path_len {
-                    if let Some((r, c, num)) = path.pop() {
-                        self.remove_and_update(r, c, num);
+                    match path.pop() {
+                        Some(SolveStep::Placed { r, c, num, .. }) => {
+                            self.remove_and_update(r, c, num);
+                        }
+                        Some(SolveStep::Eliminated { r, c, candidate, .. }) => {
+                            let restored = self.candidates_cache.get(r, c) | (1 << (candidate - 1));
+                            self.candidates_cache.set(r, c, restored);
+                        }
+                        None => {}
                     }
                 }
                 return false; // Propagation failed
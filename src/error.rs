@@ -3,16 +3,17 @@
 use thiserror::Error;
 
 /// Represents the types of errors that can occur while working with Sudoku puzzles.
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum RustokuError {
     /// The number of clues provided for puzzle generation is not between 17 and 81.
     #[error("Clues must be between 17 and 81 for a valid Sudoku puzzle")]
     InvalidClueCount,
-    /// The input string does not contain exactly 81 characters.
-    #[error("Input string must be exactly 81 characters long")]
+    /// The input string's length isn't `order^4` for any supported box order (3, 4, 5, ...).
+    #[error("Input string length must be order^4 for a supported board order")]
     InvalidInputLength,
-    /// The input string contains characters other than digits `0-9` or `.` or `_`.
-    #[error("Input string must contain only digits '0'-'9'")]
+    /// The input string contains characters other than digits/letters or `.` or `_`, or a digit
+    /// too large for the board's order.
+    #[error("Input string must contain only valid cell values, '.', or '_'")]
     InvalidInputCharacter,
     /// The initial board contains duplicate values in rows, columns, or 3x3 boxes.
     #[error("Initial board contains duplicates")]
@@ -20,4 +21,20 @@ pub enum RustokuError {
     /// The puzzle generation process failed.
     #[error("Puzzle generation failed ")]
     GenerateFailure,
+    /// A KSudoku XML record is missing its `<puzzle>` element.
+    #[error("KSudoku record is missing a required field")]
+    InvalidKSudokuRecord,
+    /// A coordinate-format record's dimension header or `row,col,value` lines are malformed,
+    /// inconsistent, or out of range for the declared board size.
+    #[error("Coordinate record is malformed or out of range for the declared board size")]
+    InvalidCoordRecord,
+    /// A `.kis` record is missing its `type`, `order`, `puzzle`, or `solution` field, or a line
+    /// isn't a `key=value` pair.
+    #[error(".kis record is missing a required field")]
+    InvalidKisRecord,
+    /// A DIMACS solution line isn't a whitespace-separated list of signed integers whose count
+    /// (minus the trailing `0`) is a perfect cube of a perfect square, as `to_dimacs`'s
+    /// `x(r, c, d)` variable numbering requires.
+    #[error("DIMACS solution line is malformed or doesn't match a supported board size")]
+    InvalidDimacsSolution,
 }
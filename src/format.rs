@@ -4,11 +4,12 @@
 //! in various ways. It also includes a simple utility to print the board to
 //! the console.
 
-use crate::core::{Board, Solution};
+use crate::core::{Difficulty, Rustoku, RustokuBoard, RustokuSolution, SolveStep};
+use crate::error::RustokuError;
 use std::fmt;
 
 /// Formats the solution into a human-readable string representation.
-impl fmt::Display for Solution {
+impl fmt::Display for RustokuSolution {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "{}", self.board)?;
         writeln!(
@@ -21,7 +22,7 @@ impl fmt::Display for Solution {
 }
 
 /// Formats the board into a human-readable string representation.
-impl fmt::Display for Board {
+impl fmt::Display for RustokuBoard {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "{}", format_grid(&self.cells).join("\n"))?;
         writeln!(f, "Line format: {}", format_line(&self.cells))?;
@@ -29,32 +30,60 @@ impl fmt::Display for Board {
     }
 }
 
+/// Formats a [`Difficulty`] as its tier name (`Easy`, `Medium`, `Hard`, `Expert`), for a
+/// human-readable `difficulty` column in tabular output (CSV, `.sdm`-adjacent reports, ...)
+/// instead of callers matching on the enum themselves.
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Medium => "Medium",
+            Difficulty::Hard => "Hard",
+            Difficulty::Expert => "Expert",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Formats a single cell value the way board strings display it: `0` as a blank dot, `1`-`9` as
+/// a digit, and anything past `9` (16x16 and 25x25 boards) as a letter (`A`, `B`, ...), mirroring
+/// `RustokuBoard`'s `TryFrom<&str>` parsing.
+fn format_cell(n: u8) -> char {
+    match n {
+        0 => '.',
+        1..=9 => (n + b'0') as char,
+        n => (n - 10 + b'A') as char,
+    }
+}
+
 /// Formats the Sudoku board into a grid representation.
 ///
-/// This function takes a 9x9 Sudoku board and formats it into a grid with
-/// horizontal and vertical separators to visually distinguish the 3x3 boxes.
-/// Each cell is represented by its number, with empty cells shown as a dot (`.`).
-pub fn format_grid(board: &[[u8; 9]; 9]) -> Vec<String> {
-    let mut grid = Vec::new();
-    let horizontal_line = "+-------+-------+-------+";
+/// This function formats a board of any order into a grid with horizontal and vertical
+/// separators to visually distinguish the boxes, inferring the order from the board's side
+/// length. Each cell is represented by [`format_cell`].
+pub fn format_grid(board: &[Vec<u8>]) -> Vec<String> {
+    let size = board.len();
+    let order = (size as f64).sqrt().round() as usize;
 
-    grid.push(horizontal_line.to_string()); // Top line
+    let segment = "-".repeat(order * 2 + 1);
+    let horizontal_line = format!("+{}+", vec![segment; order].join("+"));
 
-    for (r, row) in board.iter().enumerate().take(9) {
+    let mut grid = Vec::new();
+    grid.push(horizontal_line.clone()); // Top line
+
+    for (r, row) in board.iter().enumerate().take(size) {
         let mut line = String::from("|"); // Start of the row
-        for (c, &cell) in row.iter().enumerate().take(9) {
-            match cell {
-                0 => line.push_str(" ."), // Empty cell, two spaces for alignment
-                n => line.push_str(&format!(" {}", n)), // Number, two spaces for alignment
-            }
-            if (c + 1) % 3 == 0 {
-                line.push_str(" |"); // Vertical separator after every 3rd column
+        for (c, &cell) in row.iter().enumerate().take(size) {
+            line.push(' ');
+            line.push(format_cell(cell));
+            if (c + 1) % order == 0 {
+                line.push_str(" |"); // Vertical separator after every `order`th column
             }
         }
         grid.push(line); // Add the row to the grid
 
-        if (r + 1) % 3 == 0 {
-            grid.push(horizontal_line.to_string()); // Horizontal separator after every 3rd row
+        if (r + 1) % order == 0 {
+            grid.push(horizontal_line.clone()); // Horizontal separator after every `order`th row
         }
     }
 
@@ -63,30 +92,105 @@ pub fn format_grid(board: &[[u8; 9]; 9]) -> Vec<String> {
 
 /// Formats the Sudoku board into a single line string representation.
 ///
-/// This function converts the board into a single string where each number is
-/// represented by its digit, and empty cells are represented by a dot (`.`).
-pub fn format_line(board: &[[u8; 9]; 9]) -> String {
-    board
-        .iter()
-        .flatten()
-        .map(|&n| match n {
-            0 => '.',
-            n => (n + b'0') as char,
-        })
-        .collect()
+/// This function converts the board into a single string where each cell is represented by
+/// [`format_cell`].
+pub fn format_line(board: &[Vec<u8>]) -> String {
+    board.iter().flatten().map(|&n| format_cell(n)).collect()
+}
+
+impl RustokuBoard {
+    /// Returns this board's flat line representation, the round-trip counterpart of
+    /// `RustokuBoard::try_from(&str)`. A thin owned-`String` wrapper over [`format_line`], for
+    /// callers that want the encoding without going through `Display`.
+    pub fn to_line_string(&self) -> String {
+        format_line(&self.cells)
+    }
+
+    /// Returns this board's boxed-grid representation as an owned string, without the trailing
+    /// line-format row [`Display`](fmt::Display) adds. A thin wrapper over [`format_grid`].
+    pub fn to_pretty_string(&self) -> String {
+        format_grid(&self.cells).join("\n")
+    }
+}
+
+/// Renders a single cell's pencil marks (or its value, if filled) into an `order`-by-`order`
+/// block of characters: candidate `d` (from [`Rustoku::candidates`]) lands at row `(d-1)/order`,
+/// column `(d-1)%order` within the block, and a filled cell shows its value centered instead.
+fn format_pencil_cell(value: u8, candidates: &[u8], order: usize) -> Vec<String> {
+    let mut lines = vec![vec![' '; order]; order];
+    if value != 0 {
+        let mid = order / 2;
+        lines[mid][mid] = format_cell(value);
+    } else {
+        for &candidate in candidates {
+            let idx = (candidate - 1) as usize;
+            lines[idx / order][idx % order] = format_cell(candidate);
+        }
+    }
+    lines.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+/// Formats the board into a pencil-mark grid: every empty cell is shown as an `order`-by-`order`
+/// mini-grid of its current candidates, giving the same at-a-glance view a manual solver keeps on
+/// paper, using the same box/line borders as [`format_grid`].
+pub fn format_pencil_grid(rustoku: &Rustoku) -> Vec<String> {
+    let board = &rustoku.board;
+    let order = board.order;
+    let size = board.size();
+
+    let segment = "-".repeat(order * (order + 1) + 1);
+    let horizontal_line = format!("+{}+", vec![segment; order].join("+"));
+
+    let mut grid = Vec::new();
+    grid.push(horizontal_line.clone());
+
+    for r in 0..size {
+        let cells: Vec<Vec<String>> = (0..size)
+            .map(|c| format_pencil_cell(board.get(r, c), &rustoku.candidates(r, c), order))
+            .collect();
+
+        for mini_row in 0..order {
+            let mut line = String::from("|");
+            for (c, cell) in cells.iter().enumerate() {
+                line.push(' ');
+                line.push_str(&cell[mini_row]);
+                if (c + 1) % order == 0 {
+                    line.push_str(" |");
+                }
+            }
+            grid.push(line);
+        }
+
+        if (r + 1) % order == 0 {
+            grid.push(horizontal_line.clone());
+        }
+    }
+
+    grid
 }
 
 /// Formats a path of moves in the Sudoku solving process into a vector of strings.
 ///
-/// This function takes a vector of tuples representing moves in the format `(row, column, value)`
-/// and formats them into a human-readable string. Each move is represented as `(row, column, value)`,
-/// where `row` and `column` are 1-based indices, and `value` is the number placed in that cell.
-pub fn format_solve_path(path: &[(usize, usize, u8)]) -> Vec<String> {
+/// This function takes a slice of [`SolveStep`]s and formats them into a human-readable string.
+/// `row` and `column` are printed as 1-based indices. A [`SolveStep::Placed`] shows the number
+/// placed in that cell; a [`SolveStep::Eliminated`] shows the candidate ruled out. Either way,
+/// the technique that found the move is printed alongside it.
+pub fn format_solve_path(path: &[SolveStep]) -> Vec<String> {
     if path.is_empty() {
         vec!["(No moves recorded)".to_string()]
     } else {
         path.iter()
-            .map(|(r, c, val)| format!("({}, {}, {})", r + 1, c + 1, val))
+            .map(|step| match *step {
+                SolveStep::Placed { r, c, num, technique } => {
+                    format!("({}, {}, {}) [{:?}]", r + 1, c + 1, num, technique)
+                }
+                SolveStep::Eliminated {
+                    r,
+                    c,
+                    candidate,
+                    technique,
+                } => format!("({}, {}, ~{}) [{:?}]", r + 1, c + 1, candidate, technique),
+            })
             .collect::<Vec<String>>()
             .chunks(5) // Break into chunks of 5 moves
             .map(|chunk| chunk.join(" -> "))
@@ -94,13 +198,160 @@ pub fn format_solve_path(path: &[(usize, usize, u8)]) -> Vec<String> {
     }
 }
 
+/// Parses an `.sdm` puzzle bank: one 81-character puzzle per line, with `0` or `.` for blanks.
+///
+/// Blank lines and lines starting with `#` are skipped, matching the convention used by most
+/// `.sdm` banks found in the wild. Stops at the first line that fails to parse.
+pub fn parse_sdm(s: &str) -> Result<Vec<Rustoku>, RustokuError> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(Rustoku::new_from_str)
+        .collect()
+}
+
+/// Serializes solved boards into `.sdm` format: one 81-character line per solution.
+pub fn to_sdm(solutions: &[RustokuSolution]) -> String {
+    solutions
+        .iter()
+        .map(|solution| format_line(&solution.board.cells))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Parses a KSudoku-style XML puzzle bank into boards.
+///
+/// This reads the record shape KSudoku itself writes — `<game type=".." order="..">` elements
+/// with `<puzzle>` and `<solution>` children — but it is not a general XML parser: it has no
+/// notion of nesting, namespaces, or entity escaping beyond what a puzzle bank needs. Each
+/// `<puzzle>` is round-tripped through [`Rustoku::new_from_str`].
+pub fn parse_ksudoku(xml: &str) -> Result<Vec<Rustoku>, RustokuError> {
+    let mut puzzles = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find("<game") {
+        let Some(end) = rest[start..].find("</game>") else {
+            break;
+        };
+        let block = &rest[start..start + end];
+        let puzzle = extract_tag(block, "puzzle").ok_or(RustokuError::InvalidKSudokuRecord)?;
+        puzzles.push(Rustoku::new_from_str(puzzle.trim())?);
+        rest = &rest[start + end + "</game>".len()..];
+    }
+
+    Ok(puzzles)
+}
+
+/// Serializes solved boards into a KSudoku-style XML puzzle bank.
+///
+/// Since [`RustokuSolution`] only keeps the solved board, `<puzzle>` and `<solution>` are written
+/// identically; callers that need the original clues-only grid should track it separately before
+/// solving.
+pub fn to_ksudoku(solutions: &[RustokuSolution]) -> String {
+    let mut xml = String::from("<ksudoku>\n");
+    for solution in solutions {
+        let line = format_line(&solution.board.cells);
+        xml.push_str(&format!(
+            "  <game type=\"Sudoku\" order=\"{}\">\n    <puzzle>{line}</puzzle>\n    <solution>{line}</solution>\n  </game>\n",
+            solution.board.order,
+        ));
+    }
+    xml.push_str("</ksudoku>\n");
+    xml
+}
+
+/// Encodes a cell value the way `.kis` puzzle records do: `0` as a blank underscore, and `1..=25`
+/// as a letter starting from `b` (`b` = 1, `c` = 2, ...), leaving `a` unused so blanks and clues
+/// never share a letter. This differs from [`format_cell`]'s digit-then-letter scheme, since it's
+/// matching a representation external ksudoku tooling already writes rather than our own.
+fn encode_kis_cell(n: u8) -> char {
+    match n {
+        0 => '_',
+        n => (n - 1 + b'b') as char,
+    }
+}
+
+/// Inverse of [`encode_kis_cell`].
+fn decode_kis_cell(ch: char) -> Option<u8> {
+    match ch {
+        '_' => Some(0),
+        'b'..='z' => Some(ch as u8 - b'b' + 1),
+        _ => None,
+    }
+}
+
+/// Parses a `.kis`-style KSudoku puzzle record: a flat `key=value` block with `type`, `order`,
+/// `puzzle`, and `solution` fields, one per line. Only `order` and `puzzle` are needed to rebuild
+/// the puzzle; `type` and `solution` are accepted (and required to be present, to reject
+/// malformed records) but not otherwise interpreted, since [`Rustoku::new_from_str`] already
+/// re-derives the solution.
+pub fn parse_kis(s: &str) -> Result<Rustoku, RustokuError> {
+    let mut fields = std::collections::HashMap::new();
+    for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (key, value) = line.split_once('=').ok_or(RustokuError::InvalidKisRecord)?;
+        fields.insert(key.trim(), value.trim());
+    }
+
+    let order: usize = fields
+        .get("order")
+        .ok_or(RustokuError::InvalidKisRecord)?
+        .parse()
+        .map_err(|_| RustokuError::InvalidKisRecord)?;
+    let puzzle = fields.get("puzzle").ok_or(RustokuError::InvalidKisRecord)?;
+    fields.get("solution").ok_or(RustokuError::InvalidKisRecord)?;
+    fields.get("type").ok_or(RustokuError::InvalidKisRecord)?;
+
+    let size = order * order;
+    if puzzle.chars().count() != size * size {
+        return Err(RustokuError::InvalidInputLength);
+    }
+
+    let mut cells = vec![vec![0u8; size]; size];
+    for (i, ch) in puzzle.chars().enumerate() {
+        let value = decode_kis_cell(ch).ok_or(RustokuError::InvalidInputCharacter)?;
+        if value as usize > size {
+            return Err(RustokuError::InvalidInputCharacter);
+        }
+        cells[i / size][i % size] = value;
+    }
+
+    Rustoku::new(RustokuBoard { order, cells })
+}
+
+/// Serializes a solved board into a `.kis`-style KSudoku puzzle record.
+///
+/// Since [`RustokuSolution`] only keeps the solved board, `puzzle` and `solution` are written
+/// identically, matching [`to_ksudoku`]'s same caveat; callers that need the original clues-only
+/// grid should track it separately before solving.
+pub fn to_kis(solution: &RustokuSolution) -> String {
+    let line: String = solution.board.cells.iter().flatten().map(|&n| encode_kis_cell(n)).collect();
+    format!(
+        "type=Plain\norder={}\npuzzle={line}\nsolution={line}\n",
+        solution.board.order,
+    )
+}
+
+/// Extracts the text between `<tag>` and `</tag>` within `src`, if present.
+fn extract_tag(src: &str, tag: &str) -> Option<String> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let start = src.find(&open)? + open.len();
+    let end = start + src[start..].find(&close)?;
+    Some(src[start..end].to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::Technique;
+
+    fn grid(cells: [[u8; 9]; 9]) -> Vec<Vec<u8>> {
+        cells.iter().map(|row| row.to_vec()).collect()
+    }
 
     #[test]
     fn test_format_grid() {
-        let board = [
+        let board = grid([
             [5, 3, 0, 6, 7, 8, 9, 1, 2],
             [6, 7, 2, 1, 9, 5, 3, 4, 8],
             [1, 9, 8, 3, 4, 2, 5, 6, 7],
@@ -110,7 +361,7 @@ mod tests {
             [9, 6, 1, 5, 3, 7, 2, 8, 4],
             [2, 8, 7, 4, 1, 9, 6, 3, 5],
             [3, 4, 5, 2, 8, 6, 1, 7, 9],
-        ];
+        ]);
 
         let expected = vec![
             "+-------+-------+-------+",
@@ -133,7 +384,7 @@ mod tests {
 
     #[test]
     fn test_format_line() {
-        let board = [
+        let board = grid([
             [5, 3, 0, 6, 7, 8, 9, 1, 2],
             [6, 7, 2, 1, 9, 5, 3, 4, 8],
             [1, 9, 8, 3, 4, 2, 5, 6, 7],
@@ -143,16 +394,48 @@ mod tests {
             [9, 6, 1, 5, 3, 7, 2, 8, 4],
             [2, 8, 7, 4, 1, 9, 6, 3, 5],
             [3, 4, 5, 2, 8, 6, 1, 7, 9],
-        ];
+        ]);
 
         let expected =
             "53.678912672195348198342567859761423426853791713924856961537284287419635345286179";
         assert_eq!(expected, format_line(&board));
     }
 
+    #[test]
+    fn test_to_line_string_and_to_pretty_string_match_the_free_functions() {
+        let board = RustokuBoard::try_from(
+            "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79",
+        )
+        .expect("Board parsing failed");
+
+        assert_eq!(format_line(&board.cells), board.to_line_string());
+        assert_eq!(format_grid(&board.cells).join("\n"), board.to_pretty_string());
+    }
+
+    #[test]
+    fn test_difficulty_display_prints_tier_name() {
+        assert_eq!("Easy", Difficulty::Easy.to_string());
+        assert_eq!("Medium", Difficulty::Medium.to_string());
+        assert_eq!("Hard", Difficulty::Hard.to_string());
+        assert_eq!("Expert", Difficulty::Expert.to_string());
+    }
+
+    #[test]
+    fn test_format_grid_order_4_uses_letters_for_values_above_nine() {
+        // A 16x16 board: format_cell's letter encoding (A, B, ...) keeps every cell exactly one
+        // character wide, so format_grid's column alignment holds without needing multi-digit
+        // padding even at this order.
+        let board: Vec<Vec<u8>> = (0..16).map(|r| (1..=16u8).map(|v| (v + r) % 16 + 1).collect()).collect();
+
+        let grid = format_grid(&board);
+        assert_eq!(grid[0], "+---------+---------+---------+---------+");
+        assert!(grid.iter().all(|line| line.chars().count() == grid[0].chars().count()));
+        assert!(format_line(&board).contains('A'));
+    }
+
     #[test]
     fn test_format_grid_empty_board() {
-        let board = [[0; 9]; 9];
+        let board = grid([[0; 9]; 9]);
 
         let expected = vec![
             "+-------+-------+-------+",
@@ -175,22 +458,34 @@ mod tests {
 
     #[test]
     fn test_format_line_empty_board() {
-        let board = [[0; 9]; 9];
+        let board = grid([[0; 9]; 9]);
         let expected =
             ".................................................................................";
         assert_eq!(expected, format_line(&board));
     }
 
+    fn placed(r: usize, c: usize, num: u8, technique: Technique) -> SolveStep {
+        SolveStep::Placed { r, c, num, technique }
+    }
+
     #[test]
     fn test_format_solve_path_one_line() {
-        let path = vec![(0, 0, 5), (1, 1, 3), (2, 2, 4), (3, 3, 6), (4, 4, 7)];
-        let expected = vec!["(1, 1, 5) -> (2, 2, 3) -> (3, 3, 4) -> (4, 4, 6) -> (5, 5, 7)"];
+        let path = vec![
+            placed(0, 0, 5, Technique::NakedSingle),
+            placed(1, 1, 3, Technique::HiddenSingle),
+            placed(2, 2, 4, Technique::NakedPair),
+            placed(3, 3, 6, Technique::HiddenPair),
+            placed(4, 4, 7, Technique::LockedCandidatesPointing),
+        ];
+        let expected = vec![
+            "(1, 1, 5) [NakedSingle] -> (2, 2, 3) [HiddenSingle] -> (3, 3, 4) [NakedPair] -> (4, 4, 6) [HiddenPair] -> (5, 5, 7) [LockedCandidatesPointing]",
+        ];
         assert_eq!(expected, format_solve_path(&path));
     }
 
     #[test]
     fn test_format_solve_path_no_moves() {
-        let path: Vec<(usize, usize, u8)> = vec![];
+        let path: Vec<SolveStep> = vec![];
         let expected = vec!["(No moves recorded)".to_string()];
         assert_eq!(expected, format_solve_path(&path));
     }
@@ -198,17 +493,100 @@ mod tests {
     #[test]
     fn test_format_solve_path_multiple_lines() {
         let path = vec![
-            (0, 0, 5),
-            (1, 1, 3),
-            (2, 2, 4),
-            (3, 3, 6),
-            (4, 4, 7),
-            (5, 5, 8),
+            placed(0, 0, 5, Technique::NakedSingle),
+            placed(1, 1, 3, Technique::HiddenSingle),
+            placed(2, 2, 4, Technique::NakedPair),
+            placed(3, 3, 6, Technique::HiddenPair),
+            placed(4, 4, 7, Technique::LockedCandidatesPointing),
+            placed(5, 5, 8, Technique::XWing),
         ];
         let expected = vec![
-            "(1, 1, 5) -> (2, 2, 3) -> (3, 3, 4) -> (4, 4, 6) -> (5, 5, 7)",
-            "(6, 6, 8)",
+            "(1, 1, 5) [NakedSingle] -> (2, 2, 3) [HiddenSingle] -> (3, 3, 4) [NakedPair] -> (4, 4, 6) [HiddenPair] -> (5, 5, 7) [LockedCandidatesPointing]",
+            "(6, 6, 8) [XWing]",
         ];
         assert_eq!(expected, format_solve_path(&path));
     }
+
+    #[test]
+    fn test_format_solve_path_eliminated_step() {
+        let path = vec![SolveStep::Eliminated {
+            r: 0,
+            c: 0,
+            candidate: 5,
+            technique: Technique::NakedPair,
+        }];
+        let expected = vec!["(1, 1, ~5) [NakedPair]".to_string()];
+        assert_eq!(expected, format_solve_path(&path));
+    }
+
+    const UNIQUE_PUZZLE: &str =
+        "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79";
+    const UNIQUE_SOLUTION: &str =
+        "534678912672195348198342567859761423426853791713924856961537284287419635345286179";
+
+    #[test]
+    fn test_parse_sdm_skips_blanks_and_comments() {
+        let bank = format!("# a puzzle bank\n\n{UNIQUE_PUZZLE}\n{UNIQUE_PUZZLE}\n");
+        let puzzles = parse_sdm(&bank).expect("Parsing sdm bank failed");
+        assert_eq!(2, puzzles.len());
+    }
+
+    #[test]
+    fn test_sdm_round_trips_through_solve() {
+        let mut rustoku = Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed");
+        let solution = rustoku.solve_any().expect("Solving puzzle failed");
+        let sdm = to_sdm(&[solution]);
+        assert_eq!(UNIQUE_SOLUTION, sdm);
+    }
+
+    #[test]
+    fn test_ksudoku_round_trips_through_solve() {
+        let mut rustoku = Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed");
+        let solution = rustoku.solve_any().expect("Solving puzzle failed");
+        let xml = to_ksudoku(&[solution]);
+
+        let parsed = parse_ksudoku(&xml).expect("Parsing KSudoku bank failed");
+        assert_eq!(1, parsed.len());
+        assert_eq!(UNIQUE_SOLUTION, format_line(&parsed[0].board.cells));
+    }
+
+    #[test]
+    fn test_parse_ksudoku_rejects_missing_puzzle() {
+        let xml = "<ksudoku><game type=\"Sudoku\" order=\"3\"></game></ksudoku>";
+        let result = parse_ksudoku(xml);
+        assert!(matches!(
+            result,
+            Err(RustokuError::InvalidKSudokuRecord)
+        ));
+    }
+
+    #[test]
+    fn test_kis_round_trips_through_solve() {
+        let mut rustoku = Rustoku::new_from_str(UNIQUE_PUZZLE).expect("Rustoku creation failed");
+        let solution = rustoku.solve_any().expect("Solving puzzle failed");
+        let kis = to_kis(&solution);
+
+        assert!(kis.contains("type=Plain"));
+        assert!(kis.contains("order=3"));
+
+        let parsed = parse_kis(&kis).expect("Parsing .kis record failed");
+        assert_eq!(UNIQUE_SOLUTION, format_line(&parsed.board.cells));
+    }
+
+    #[test]
+    fn test_kis_cell_encoding_offsets_letters_past_a() {
+        assert_eq!('_', encode_kis_cell(0));
+        assert_eq!('b', encode_kis_cell(1));
+        assert_eq!('c', encode_kis_cell(2));
+        assert_eq!(Some(0), decode_kis_cell('_'));
+        assert_eq!(Some(1), decode_kis_cell('b'));
+        assert_eq!(None, decode_kis_cell('a'));
+    }
+
+    #[test]
+    fn test_parse_kis_rejects_missing_field() {
+        let record = format!("type=Plain\norder=3\npuzzle={}\n", "_".repeat(81));
+        let result = parse_kis(&record);
+        assert!(matches!(result, Err(RustokuError::InvalidKisRecord)));
+    }
 }
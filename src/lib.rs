@@ -5,9 +5,11 @@
 //! rows, columns, and 3x3 boxes, enabling fast validation and candidate computation
 //! during the solving process.
 //!
-//! The library also provides a `print_board` utility to print the Sudoku board in a
-//! human-readable format. The output includes the matrix-like representation of the
-//! board as well as the one-line representation for easy copying and pasting.
+//! [`core::RustokuBoard`] also implements [`std::fmt::Display`] for a human-readable format that
+//! includes the matrix-like representation of the board as well as the one-line representation
+//! for easy copying and pasting; [`core::RustokuBoard::to_pretty_string`] and
+//! [`core::RustokuBoard::to_line_string`] return either half as an owned `String`.
 
 pub mod core;
+pub mod error;
 pub mod format;
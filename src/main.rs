@@ -1,5 +1,10 @@
 use clap::{Parser, Subcommand};
-use rustoku::core::{Rustoku, TechniqueMask, generate_board};
+use rustoku::core::{
+    Difficulty, Rustoku, RustokuTechniques, generate_board, generate_board_with_difficulty,
+};
+use rustoku::error::RustokuError;
+use rustoku::format::{format_pencil_grid, parse_ksudoku, to_ksudoku};
+use std::io::Read;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -17,9 +22,15 @@ struct Cli {
 enum Commands {
     /// Generates a new Sudoku puzzle with a unique solution
     Generate {
-        /// The desired number of initially filled cells (clues) for the puzzle
+        /// The desired number of initially filled cells (clues) for the puzzle. Ignored if
+        /// `--difficulty` is given, since digging for a target difficulty controls clue count
+        /// itself.
         #[arg(short, long, default_value_t = 30)] // Default to 30 clues
         clues: usize,
+        /// Generate for a target difficulty tier (easy, medium, hard, expert) instead of a clue
+        /// count: clues are dug until the puzzle's solve path grades at this tier.
+        #[arg(short, long)]
+        difficulty: Option<String>,
     },
     /// Solves a given Sudoku puzzle
     Solve {
@@ -28,13 +39,54 @@ enum Commands {
     },
     /// Checks if a given Sudoku puzzle is solved correctly
     Check {
-        /// The Sudoku puzzle string (81 characters: 0-9 or . or _)
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
         puzzle: String,
     },
     /// Shows the Sudoku puzzle in a grid-like format
     Show {
-        /// The Sudoku puzzle string (81 characters: 0-9 or . or _)
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
         puzzle: String,
+        /// Show each empty cell's candidates as a pencil-mark mini-grid instead of a blank dot.
+        #[arg(short, long)]
+        candidates: bool,
+    },
+    /// Imports a KSudoku-format puzzle bank and shows each puzzle's grid
+    ImportKsudoku {
+        /// The KSudoku XML content.
+        xml: String,
+    },
+    /// Solves a puzzle and exports the solution as a KSudoku-format puzzle bank
+    ExportKsudoku {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+    },
+    /// Loads a KSudoku-format puzzle bank from a file and shows each puzzle's grid
+    Load {
+        /// Path to the KSudoku XML file.
+        path: String,
+    },
+    /// Exports a puzzle's SAT/CNF encoding in DIMACS format, for external SAT solvers
+    Export {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+    },
+    /// Grades a puzzle's difficulty from the techniques its solve path requires
+    Grade {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+    },
+    /// Solves a puzzle and saves the solution as a KSudoku-format puzzle bank to a file
+    Save {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+        /// Path to write the KSudoku XML file to.
+        path: String,
     },
 }
 
@@ -42,34 +94,129 @@ enum Commands {
 enum SolveCommands {
     /// Attempts to find any puzzle solution with easy techniques
     Any {
-        /// The Sudoku puzzle string.
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
         puzzle: String,
+        /// Also print the puzzle's graded difficulty tier before the solution.
+        #[arg(short, long)]
+        verbose: bool,
     },
     /// Attempts to find all puzzle solutions with easy techniques
     All {
-        /// The Sudoku puzzle string.
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
         puzzle: String,
+        /// Also print the puzzle's graded difficulty tier before the solutions.
+        #[arg(short, long)]
+        verbose: bool,
     },
     /// Attempts to find any puzzle solution with all techniques
     Human {
-        /// The Sudoku puzzle string.
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+        /// Also print the puzzle's graded difficulty tier before the solution.
+        #[arg(short, long)]
+        verbose: bool,
+    },
+    /// Attempts to find any puzzle solution via a SAT/CNF encoding
+    Sat {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
         puzzle: String,
+        /// Also print the puzzle's graded difficulty tier before the solution.
+        #[arg(short, long)]
+        verbose: bool,
     },
+    /// Counts solutions without materializing them, e.g. to cheaply check uniqueness
+    Count {
+        /// The Sudoku puzzle string (81 characters: 0-9 or . or _), the coordinate-triple
+        /// stream format, or `-` to read either from stdin.
+        puzzle: String,
+        /// Stop counting once this many solutions are found (pass 2 to just check uniqueness).
+        #[arg(short, long, default_value_t = usize::MAX)]
+        limit: usize,
+    },
+}
+
+/// Reads a puzzle argument, resolving `-` to stdin, and parses it as either the flat board string
+/// or the coordinate-triple stream format (auto-detected by whether the first line looks like an
+/// `N,N` dimension header), so every subcommand accepting a puzzle can be scripted in a pipeline.
+fn read_puzzle(puzzle: &str) -> Result<Rustoku, RustokuError> {
+    let content = if puzzle == "-" {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf).unwrap_or_else(|e| {
+            eprintln!("Error reading stdin: {e}");
+            std::process::exit(1);
+        });
+        buf
+    } else {
+        puzzle.to_string()
+    };
+
+    let looks_like_coord_header = content
+        .lines()
+        .next()
+        .and_then(|line| line.trim().split_once(','))
+        .is_some_and(|(a, b)| {
+            a.trim().parse::<usize>().is_ok() && b.trim().parse::<usize>().is_ok()
+        });
+
+    if looks_like_coord_header {
+        Rustoku::from_coord_lines(&content)
+    } else {
+        Rustoku::new_from_str(content.trim())
+    }
+}
+
+/// Parses a `--difficulty` argument (case-insensitive `easy`/`medium`/`hard`/`expert`), exiting
+/// with an error message if it doesn't match one of the four tiers.
+fn parse_difficulty(s: &str) -> Difficulty {
+    match s.to_lowercase().as_str() {
+        "easy" => Difficulty::Easy,
+        "medium" => Difficulty::Medium,
+        "hard" => Difficulty::Hard,
+        "expert" => Difficulty::Expert,
+        other => {
+            eprintln!("Error: unknown difficulty '{other}' (expected easy, medium, hard, or expert)");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Prints the puzzle's graded difficulty tier, if `verbose` is set and grading succeeds (grading
+/// fails only when the puzzle has no solution).
+fn print_grade_if_verbose(rustoku: &Rustoku, verbose: bool) {
+    if verbose {
+        if let Some(grade) = rustoku.grade() {
+            println!("Difficulty: {}", grade.difficulty);
+        }
+    }
 }
 
 fn main() {
     let cli = Cli::parse();
 
     let result = match cli.command {
-        Commands::Generate { clues } => generate_board(clues).map(|board| print!("{}", board)),
+        Commands::Generate { clues, difficulty } => match difficulty {
+            None => generate_board(clues).map(|board| print!("{}", board)),
+            Some(difficulty) => {
+                generate_board_with_difficulty(parse_difficulty(&difficulty)).map(|puzzle| {
+                    println!("Difficulty: {}", puzzle.difficulty);
+                    print!("{}", puzzle.board);
+                })
+            }
+        },
         Commands::Solve { solve_command } => match solve_command {
-            SolveCommands::Any { puzzle } => {
-                Rustoku::new_from_str(&puzzle).map(|mut rustoku| match rustoku.solve_any() {
+            SolveCommands::Any { puzzle, verbose } => read_puzzle(&puzzle).map(|mut rustoku| {
+                print_grade_if_verbose(&rustoku, verbose);
+                match rustoku.solve_any() {
                     None => println!("No solution found."),
                     Some(solution) => print!("{}", solution),
-                })
-            }
-            SolveCommands::All { puzzle } => Rustoku::new_from_str(&puzzle).map(|mut rustoku| {
+                }
+            }),
+            SolveCommands::All { puzzle, verbose } => read_puzzle(&puzzle).map(|mut rustoku| {
+                print_grade_if_verbose(&rustoku, verbose);
                 let solutions = rustoku.solve_all();
                 if solutions.is_empty() {
                     println!("No solutions found.");
@@ -81,22 +228,86 @@ fn main() {
                     println!("\nFound {} solution(s).", solutions.len());
                 }
             }),
-            SolveCommands::Human { puzzle } => Rustoku::new_from_str(&puzzle).map(|rustoku| {
-                match rustoku.with_techniques(TechniqueMask::all()).solve_any() {
+            SolveCommands::Human { puzzle, verbose } => read_puzzle(&puzzle).map(|rustoku| {
+                print_grade_if_verbose(&rustoku, verbose);
+                match rustoku.with_techniques(RustokuTechniques::ALL).solve_any() {
                     None => println!("No solution found."),
                     Some(solution) => print!("{}", solution),
                 }
             }),
+            SolveCommands::Sat { puzzle, verbose } => read_puzzle(&puzzle).map(|rustoku| {
+                print_grade_if_verbose(&rustoku, verbose);
+                match rustoku.solve_any_sat() {
+                    None => println!("No solution found."),
+                    Some(solution) => print!("{}", solution),
+                }
+            }),
+            SolveCommands::Count { puzzle, limit } => read_puzzle(&puzzle).map(|mut rustoku| {
+                println!("{}", rustoku.count_solutions(limit));
+            }),
         },
-        Commands::Check { puzzle } => Rustoku::new_from_str(&puzzle).map(|rustoku| {
+        Commands::Check { puzzle } => read_puzzle(&puzzle).map(|rustoku| {
             println!(
                 "The puzzle is {}solved correctly.",
                 if rustoku.is_solved() { "" } else { "NOT " }
             );
         }),
-        Commands::Show { puzzle } => Rustoku::new_from_str(&puzzle).map(|rustoku| {
-            print!("{}", rustoku.board);
+        Commands::Show { puzzle, candidates } => read_puzzle(&puzzle).map(|rustoku| {
+            if candidates {
+                println!("{}", format_pencil_grid(&rustoku).join("\n"));
+            } else {
+                print!("{}", rustoku.board);
+            }
+        }),
+        Commands::Export { puzzle } => read_puzzle(&puzzle).map(|rustoku| {
+            print!("{}", rustoku.board.to_dimacs());
         }),
+        Commands::Grade { puzzle } => read_puzzle(&puzzle).map(|rustoku| match rustoku.grade() {
+            None => println!("Puzzle could not be graded (no solution found)."),
+            Some(report) => {
+                println!("Difficulty: {}", report.difficulty);
+                println!("Requires guessing: {}", report.requires_guessing);
+                println!("Technique counts:");
+                let mut counts: Vec<_> = report.technique_counts.into_iter().collect();
+                counts.sort_by_key(|(technique, _)| format!("{technique:?}"));
+                for (technique, count) in counts {
+                    println!("  {technique:?}: {count}");
+                }
+            }
+        }),
+        Commands::ImportKsudoku { xml } => parse_ksudoku(&xml).map(|puzzles| {
+            for rustoku in puzzles {
+                print!("{}", rustoku.board);
+            }
+        }),
+        Commands::ExportKsudoku { puzzle } => {
+            read_puzzle(&puzzle).map(|mut rustoku| match rustoku.solve_any() {
+                None => println!("No solution found."),
+                Some(solution) => print!("{}", to_ksudoku(&[solution])),
+            })
+        }
+        Commands::Load { path } => {
+            let xml = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+                eprintln!("Error reading {path}: {e}");
+                std::process::exit(1);
+            });
+            parse_ksudoku(&xml).map(|puzzles| {
+                for rustoku in puzzles {
+                    print!("{}", rustoku.board);
+                }
+            })
+        }
+        Commands::Save { puzzle, path } => {
+            read_puzzle(&puzzle).map(|mut rustoku| match rustoku.solve_any() {
+                None => println!("No solution found."),
+                Some(solution) => {
+                    if let Err(e) = std::fs::write(&path, to_ksudoku(&[solution])) {
+                        eprintln!("Error writing {path}: {e}");
+                        std::process::exit(1);
+                    }
+                }
+            })
+        }
     };
 
     if let Err(e) = result {
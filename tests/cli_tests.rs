@@ -49,6 +49,7 @@ fn test_generate_custom_clues() {
 fn test_solve_valid_puzzle() {
     get_rustoku_bin()
         .arg("solve")
+        .arg("any")
         .arg("53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79")
         .assert()
         .success()
@@ -61,6 +62,7 @@ fn test_solve_valid_puzzle() {
 fn test_solve_invalid_puzzle_length() {
     get_rustoku_bin()
         .arg("solve")
+        .arg("any")
         .arg("short") // Invalid length
         .assert()
         .failure() // Expect the command to fail
@@ -73,8 +75,8 @@ fn test_solve_invalid_puzzle_length() {
 fn test_solve_all_solutions() {
     get_rustoku_bin()
         .arg("solve")
+        .arg("all")
         .arg("2957438614318659..8761925433874592166123874955492167387635.41899286713541549386..")
-        .arg("--all")
         .assert()
         .success()
         .stdout(predicates::str::contains("Found 2 solution(s).")); // Based on your example, this puzzle has 2 solutions